@@ -1,21 +1,31 @@
 use anyhow::{anyhow, bail, Context, Result};
-use clap::{Args, Parser, Subcommand};
-use serde::Deserialize;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use figment::providers::{Env, Format, Toml};
+use figment::{Figment, Source};
+use serde::{Deserialize, Serialize};
+use ssh2::Session;
+use std::collections::VecDeque;
 use std::env;
 use std::fs;
-use std::io::{self, Write};
+use std::io::{self, BufRead, IsTerminal, Read, Write};
+use std::net::{IpAddr, TcpStream, ToSocketAddrs};
 use std::path::{Path, PathBuf};
 use std::process::{Command, Output};
+use std::sync::Mutex;
 use std::thread::sleep;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 const CONFIG_FILE_NAME: &str = "config.toml";
 const SSH_CONFIG_FILE: &str = "ssh_config";
+const JOURNAL_FILE_NAME: &str = "journal.json";
+const AUDIT_LOG_FILE_NAME: &str = "audit.jsonl";
 const EC2_PROVIDER: &str = "ec2";
 const LIGHTSAIL_PROVIDER: &str = "lightsail";
 const GCE_PROVIDER: &str = "gce";
 const DROPLET_PROVIDER: &str = "droplet";
+const OPENSTACK_PROVIDER: &str = "openstack";
 const DEFAULT_INSTANCE_TYPE: &str = "t3.micro";
+const DEFAULT_VPC_CIDR: &str = "10.0.0.0/16";
 const DEFAULT_INSTANCE_OS_USER: &str = "ubuntu";
 const DEFAULT_CONFIG_DIR: &str = "~/.config/vmcli";
 const DEFAULT_LIGHTSAIL_BUNDLE_ID: &str = "nano_3_0";
@@ -29,22 +39,113 @@ const DEFAULT_DROPLET_IMAGE: &str = "ubuntu-24-04-x64";
 const UBUNTU_2404_AMI_SSM: &str =
     "/aws/service/canonical/ubuntu/server/24.04/stable/current/amd64/hvm/ebs-gp3/ami-id";
 const NON_TERMINATED_STATES: &str = "pending,running,stopping,stopped,shutting-down";
+const AWS_RETRY_MAX_ATTEMPTS: u32 = 5;
+const AWS_RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+const AWS_RETRY_MAX_DELAY: Duration = Duration::from_secs(20);
+const RESOURCE_VISIBILITY_TIMEOUT: Duration = Duration::from_secs(30);
+/// How long an EC2 Instance Connect pushed public key remains valid; also
+/// used as the `ssh-add -t` lifetime so agent-loaded keys expire alongside it.
+const EIC_KEY_LIFETIME_SECS: u64 = 60;
+const INSTANCE_RUNNING_TIMEOUT: Duration = Duration::from_secs(300);
+const INSTANCE_STATUS_CHECKS_TIMEOUT: Duration = Duration::from_secs(300);
 
 #[derive(Parser)]
 #[command(name = "vmcli", version, about = "vmcli multi-cloud helper")]
 struct Cli {
     #[arg(long = "config-dir", global = true, default_value = DEFAULT_CONFIG_DIR)]
     config_dir: String,
+    #[arg(long = "backend", global = true, value_enum, default_value = "cli")]
+    backend: AwsBackend,
+    /// Named AWS profile from ~/.aws/config or ~/.aws/credentials; embedded
+    /// into every `aws` invocation instead of relying on AWS_PROFILE.
+    #[arg(long = "profile", global = true)]
+    profile: Option<String>,
     #[command(subcommand)]
     command: TopCommand,
 }
 
+/// Selects the implementation `AwsCli` uses for the AWS calls that have two
+/// paths: the `aws` CLI subprocess, or the native `aws-sdk-ec2`/`aws-sdk-ssm`
+/// clients. The SDK-backed calls are `describe-instances`,
+/// `describe-security-groups`, `create-route`/`replace-route`,
+/// `associate-route-table`, `create-security-group`,
+/// `authorize`/`revoke-security-group-ingress` and the SSM AMI lookup;
+/// everything else (the other provisioning calls, lightsail, gce, droplet,
+/// openstack) shells out under either backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+enum AwsBackend {
+    #[default]
+    Cli,
+    Sdk,
+}
+
 #[derive(Subcommand)]
 enum TopCommand {
     Ec2(Ec2Args),
     Lightsail(LightsailArgs),
     Gce(GceArgs),
     Droplet(DropletArgs),
+    Openstack(OpenstackArgs),
+    /// Aggregated status across every configured provider/cluster.
+    Status(GlobalStatusArgs),
+    /// Alias for `status --all`.
+    Ls(GlobalStatusArgs),
+    /// Inspect and validate vmcli configuration.
+    Config(ConfigArgs),
+}
+
+#[derive(Args)]
+struct ConfigArgs {
+    #[command(subcommand)]
+    command: ConfigCommand,
+}
+
+#[derive(Subcommand)]
+enum ConfigCommand {
+    /// Validate every configured cluster's config (all layers, all providers)
+    /// without touching any cloud API; reports the first problem found in each
+    /// failing cluster and exits non-zero if any cluster failed validation.
+    Validate(ConfigValidateArgs),
+}
+
+#[derive(Args)]
+struct ConfigValidateArgs {
+    /// Restrict to a single cluster name.
+    #[arg(long = "cluster")]
+    cluster: Option<String>,
+    /// Restrict to a single provider (ec2, lightsail, gce, droplet, openstack).
+    #[arg(long = "provider")]
+    provider: Option<String>,
+    /// Output format for the validation report.
+    #[arg(long = "output", value_enum, default_value = "table")]
+    output: GlobalStatusOutput,
+}
+
+#[derive(Args)]
+struct GlobalStatusArgs {
+    /// Query every configured provider/cluster (narrowed by `--provider` and
+    /// `--cluster`); required, since `status` has no single-cluster mode.
+    #[arg(long = "all")]
+    all: bool,
+    /// Worker threads used to query clusters concurrently (default: number of CPUs).
+    #[arg(long = "concurrency")]
+    concurrency: Option<usize>,
+    /// Restrict to a single provider (ec2, lightsail, gce, droplet, openstack).
+    #[arg(long = "provider")]
+    provider: Option<String>,
+    /// Restrict to a single cluster name.
+    #[arg(long = "cluster")]
+    cluster: Option<String>,
+    /// Output format for the aggregated inventory.
+    #[arg(long = "output", value_enum, default_value = "table")]
+    output: GlobalStatusOutput,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+enum GlobalStatusOutput {
+    #[default]
+    Table,
+    Json,
 }
 
 #[derive(Args)]
@@ -71,16 +172,39 @@ struct DropletArgs {
     command: DropletCommand,
 }
 
+#[derive(Args)]
+struct OpenstackArgs {
+    #[command(subcommand)]
+    command: OpenstackCommand,
+}
+
 #[derive(Subcommand)]
 enum Ec2Command {
     Init(InitArgs),
     Up(Ec2UpArgs),
+    /// Provision multiple named nodes concurrently against a shared
+    /// VPC/subnet/security-group/key-pair, optionally co-located in a
+    /// placement group.
+    ScaleUp(Ec2ScaleUpArgs),
     Status(StatusArgs),
     Health(Ec2HealthArgs),
+    Exec(Ec2ExecArgs),
+    /// Push a ~60s ephemeral EC2 Instance Connect key and open an interactive
+    /// SSH session, without managing key distribution or `~/.ssh/config` by hand.
+    Ssh(Ec2SshArgs),
     Reboot(RebootArgs),
     Destroy(DestroyArgs),
     Prune(PruneArgs),
     Regions(ListRegionsArgs),
+    /// Print the fully-resolved effective config and the source of each field.
+    Config(StatusArgs),
+    /// Terminate spot/on-demand instances past their `--lifetime` expiry.
+    Reap(ReapArgs),
+    /// Reconcile the cluster security group against the configured `[[firewall]]` rules.
+    Firewall(FirewallArgs),
+    /// Enumerate the cluster's VPC/subnet/gateway/route-table/security-group
+    /// resources and their present/absent state.
+    Resources(ResourcesArgs),
 }
 
 #[derive(Subcommand)]
@@ -89,10 +213,15 @@ enum LightsailCommand {
     Up(LightsailUpArgs),
     Status(StatusArgs),
     Health(HealthArgs),
+    Exec(ExecArgs),
     Reboot(RebootArgs),
     Destroy(DestroyArgs),
     Prune(PruneArgs),
     Regions(ListRegionsArgs),
+    /// Print the fully-resolved effective config and the source of each field.
+    Config(StatusArgs),
+    /// Reconcile the instance's public ports against the configured `[[firewall]]` rules.
+    Firewall(FirewallArgs),
 }
 
 #[derive(Subcommand)]
@@ -101,11 +230,14 @@ enum GceCommand {
     Up(GceUpArgs),
     Status(StatusArgs),
     Health(HealthArgs),
+    Exec(ExecArgs),
     Reboot(RebootArgs),
     Destroy(DestroyArgs),
     Prune(PruneArgs),
     Regions(ListRegionsArgs),
     Zones(GceZonesArgs),
+    /// Print the fully-resolved effective config and the source of each field.
+    Config(StatusArgs),
 }
 
 #[derive(Subcommand)]
@@ -114,15 +246,45 @@ enum DropletCommand {
     Up(DropletUpArgs),
     Status(StatusArgs),
     Health(HealthArgs),
+    Exec(ExecArgs),
+    Reboot(RebootArgs),
+    Destroy(DestroyArgs),
+    Prune(PruneArgs),
+    Regions(ListRegionsArgs),
+    /// Print the fully-resolved effective config and the source of each field.
+    Config(StatusArgs),
+}
+
+#[derive(Subcommand)]
+enum OpenstackCommand {
+    Init(InitArgs),
+    Up(OpenstackUpArgs),
+    Status(StatusArgs),
+    Health(HealthArgs),
+    Exec(ExecArgs),
     Reboot(RebootArgs),
     Destroy(DestroyArgs),
     Prune(PruneArgs),
     Regions(ListRegionsArgs),
+    /// Print the fully-resolved effective config and the source of each field.
+    Config(StatusArgs),
 }
 
 #[derive(Args)]
 struct InitArgs {
     cluster: String,
+    /// Interactively prompt for each config field instead of using defaults.
+    #[arg(long = "wizard")]
+    wizard: bool,
+    /// Overwrite an existing config.toml (required alongside --wizard).
+    #[arg(short = 'f', long = "force")]
+    force: bool,
+    /// Droplet-only: query doctl's live region/size/image catalogs and present
+    /// numbered menus instead of free-text prompts. When stdin isn't a TTY it
+    /// has no effect: the static default template is written (or `--wizard`'s
+    /// prompts run, if `--wizard` is also passed).
+    #[arg(long = "interactive")]
+    interactive: bool,
 }
 
 #[derive(Args)]
@@ -132,6 +294,24 @@ struct StatusArgs {
     config: Option<String>,
 }
 
+#[derive(Args)]
+struct ResourcesArgs {
+    cluster: String,
+    #[arg(short = 'c', long = "config")]
+    config: Option<String>,
+    /// Output format for the resource inventory.
+    #[arg(long = "output", value_enum, default_value = "table")]
+    output: ResourcesOutput,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+enum ResourcesOutput {
+    #[default]
+    Table,
+    Json,
+    Csv,
+}
+
 #[derive(Args)]
 struct HealthArgs {
     cluster: String,
@@ -140,6 +320,64 @@ struct HealthArgs {
     config: Option<String>,
 }
 
+#[derive(Args)]
+struct Ec2ExecArgs {
+    cluster: String,
+    /// Instance name to run the command on; omit (pass `--` before the command)
+    /// to run it on every instance in the cluster.
+    name: Option<String>,
+    #[arg(short = 'c', long = "config")]
+    config: Option<String>,
+    #[arg(long = "os-user", default_value = DEFAULT_INSTANCE_OS_USER)]
+    os_user: String,
+    #[arg(long = "boot-timeout", default_value_t = 120)]
+    boot_timeout_secs: u64,
+    /// Worker threads used to run the command concurrently when `name` is omitted.
+    #[arg(long = "concurrency")]
+    concurrency: Option<usize>,
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true, required = true)]
+    command: Vec<String>,
+}
+
+#[derive(Args)]
+struct Ec2SshArgs {
+    cluster: String,
+    name: String,
+    #[arg(short = 'c', long = "config")]
+    config: Option<String>,
+    #[arg(long = "ssh-user", default_value = DEFAULT_INSTANCE_OS_USER)]
+    ssh_user: String,
+    #[arg(long = "ssh-port", default_value_t = 22)]
+    ssh_port: u16,
+    /// Private key file to authenticate with; defaults to the vmcli-managed
+    /// key pair derived from `ssh_public_key_path`.
+    #[arg(long = "identity-file")]
+    identity_file: Option<String>,
+    /// Load the private key into the running ssh-agent (`SSH_AUTH_SOCK`) for
+    /// the EIC key's validity window instead of passing `-i` on the `ssh`
+    /// command line, so no long-lived key ever needs to leave the agent.
+    /// Falls back to identity-file mode with a warning if no agent is running.
+    #[arg(long = "use-agent")]
+    use_agent: bool,
+}
+
+#[derive(Args)]
+struct ExecArgs {
+    cluster: String,
+    /// Instance name to run the command on; omit (pass `--` before the command)
+    /// to run it on every instance in the cluster.
+    name: Option<String>,
+    #[arg(short = 'c', long = "config")]
+    config: Option<String>,
+    #[arg(long = "boot-timeout", default_value_t = 120)]
+    boot_timeout_secs: u64,
+    /// Worker threads used to run the command concurrently when `name` is omitted.
+    #[arg(long = "concurrency")]
+    concurrency: Option<usize>,
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true, required = true)]
+    command: Vec<String>,
+}
+
 #[derive(Args)]
 struct RebootArgs {
     cluster: String,
@@ -167,6 +405,68 @@ struct PruneArgs {
     config: Option<String>,
 }
 
+#[derive(Args)]
+struct ReapArgs {
+    cluster: String,
+    #[arg(short = 'c', long = "config")]
+    config: Option<String>,
+}
+
+#[derive(Args)]
+struct FirewallArgs {
+    cluster: String,
+    #[arg(value_enum)]
+    action: FirewallAction,
+    #[arg(short = 'c', long = "config")]
+    config: Option<String>,
+}
+
+/// Reconciliation applied by `firewall`: `List` is read-only, `Allow`/`Revoke`
+/// apply the additions/removals between the configured `[[firewall]]` rules
+/// and what the provider currently has open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum FirewallAction {
+    List,
+    Allow,
+    Revoke,
+}
+
+/// Mirrors EC2's `InstanceInterruptionBehavior` for spot requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum SpotInterruptionBehavior {
+    Terminate,
+    Stop,
+    Hibernate,
+}
+
+impl SpotInterruptionBehavior {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SpotInterruptionBehavior::Terminate => "terminate",
+            SpotInterruptionBehavior::Stop => "stop",
+            SpotInterruptionBehavior::Hibernate => "hibernate",
+        }
+    }
+}
+
+/// Mirrors EC2's placement group `Strategy` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum PlacementGroupStrategy {
+    Cluster,
+    Spread,
+    Partition,
+}
+
+impl PlacementGroupStrategy {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PlacementGroupStrategy::Cluster => "cluster",
+            PlacementGroupStrategy::Spread => "spread",
+            PlacementGroupStrategy::Partition => "partition",
+        }
+    }
+}
+
 #[derive(Args)]
 struct Ec2UpArgs {
     cluster: String,
@@ -175,16 +475,103 @@ struct Ec2UpArgs {
     instance_type: Option<String>,
     #[arg(short = 'c', long = "config")]
     config: Option<String>,
+    /// Path to a cloud-init/user-data file to run at boot.
+    #[arg(long = "user-data")]
+    user_data: Option<String>,
+    /// Literal cloud-init/user-data content to run at boot.
+    #[arg(long = "user-data-inline")]
+    user_data_inline: Option<String>,
+    /// Additional public key file to grant access; may be repeated.
+    #[arg(long = "ssh-key")]
+    ssh_key: Vec<String>,
+    /// Launch as a spot instance instead of on-demand.
+    #[arg(long = "spot")]
+    spot: bool,
+    /// Maximum hourly spot price; only valid with --spot.
+    #[arg(long = "spot-max-price")]
+    spot_max_price: Option<String>,
+    /// What AWS should do with the spot instance on interruption; only valid with --spot.
+    #[arg(long = "spot-interruption-behavior", value_enum, default_value = "terminate")]
+    spot_interruption_behavior: SpotInterruptionBehavior,
+    /// Request a persistent spot request that AWS will keep refilling after
+    /// interruption, instead of a one-time request; only valid with --spot.
+    #[arg(long = "spot-persistent")]
+    spot_persistent: bool,
+    /// If the spot request is rejected for price/capacity, silently fall back
+    /// to an on-demand launch instead of failing; only valid with --spot.
+    #[arg(long = "spot-fallback-on-demand")]
+    spot_fallback_on_demand: bool,
+    /// Tag the instance to expire after this duration (e.g. "2h", "30m", "1d")
+    /// so `vmcli ec2 reap` can terminate it automatically.
+    #[arg(long = "lifetime")]
+    lifetime: Option<String>,
+    /// Preview the VPC/subnet/security-group/instance changes `up` would make
+    /// without mutating AWS; prints a Terraform-style plan and exits.
+    #[arg(long = "dry-run")]
+    dry_run: bool,
+}
+
+#[derive(Args)]
+struct Ec2ScaleUpArgs {
+    cluster: String,
+    /// Nodes are named "<name-prefix>-0", "<name-prefix>-1", etc.
+    name_prefix: String,
+    /// Number of nodes to provision.
+    #[arg(short = 'n', long = "count")]
+    count: usize,
+    #[arg(short = 'T', long = "instance-type")]
+    instance_type: Option<String>,
+    #[arg(short = 'c', long = "config")]
+    config: Option<String>,
+    /// Path to a cloud-init/user-data file to run at boot.
+    #[arg(long = "user-data")]
+    user_data: Option<String>,
+    /// Literal cloud-init/user-data content to run at boot.
+    #[arg(long = "user-data-inline")]
+    user_data_inline: Option<String>,
+    /// Additional public key file to grant access; may be repeated.
+    #[arg(long = "ssh-key")]
+    ssh_key: Vec<String>,
+    /// Worker threads used to launch nodes concurrently.
+    #[arg(long = "concurrency")]
+    concurrency: Option<usize>,
+    /// Co-locate the nodes in an EC2 placement group using this strategy, for
+    /// low-latency distributed workloads.
+    #[arg(long = "placement-group-strategy", value_enum)]
+    placement_group_strategy: Option<PlacementGroupStrategy>,
+    /// Number of partitions; only valid with `--placement-group-strategy partition`.
+    #[arg(long = "placement-group-partitions")]
+    placement_group_partitions: Option<u32>,
+    /// Tag every instance to expire after this duration (e.g. "2h", "30m", "1d")
+    /// so `vmcli ec2 reap` can terminate it automatically.
+    #[arg(long = "lifetime")]
+    lifetime: Option<String>,
+    /// Preview the shared VPC/subnet/security-group/placement-group changes
+    /// `scale-up` would make without mutating AWS; prints a Terraform-style
+    /// plan and exits.
+    #[arg(long = "dry-run")]
+    dry_run: bool,
 }
 
 #[derive(Args)]
 struct Ec2HealthArgs {
     cluster: String,
-    name: String,
+    /// Instance name to check; omit to check every instance in the cluster.
+    name: Option<String>,
     #[arg(short = 'c', long = "config")]
     config: Option<String>,
     #[arg(long = "os-user", default_value = DEFAULT_INSTANCE_OS_USER)]
     os_user: String,
+    /// Worker threads used to check instances concurrently when `name` is omitted.
+    #[arg(long = "concurrency")]
+    concurrency: Option<usize>,
+    /// Timeout in seconds for the direct TCP/SSH reachability probe on port 22.
+    #[arg(long = "tcp-timeout-secs", default_value_t = 5)]
+    tcp_timeout_secs: u64,
+    /// Print the full report (instance metadata, status checks, EIC probe,
+    /// TCP/SSH probe, summary) as JSON instead of key=value lines.
+    #[arg(long = "json")]
+    json: bool,
 }
 
 #[derive(Args)]
@@ -195,6 +582,18 @@ struct LightsailUpArgs {
     bundle_id: Option<String>,
     #[arg(short = 'c', long = "config")]
     config: Option<String>,
+    /// Path to a cloud-init/user-data file to run at boot.
+    #[arg(long = "user-data")]
+    user_data: Option<String>,
+    /// Literal cloud-init/user-data content to run at boot.
+    #[arg(long = "user-data-inline")]
+    user_data_inline: Option<String>,
+    /// Additional public key file to grant access; may be repeated.
+    #[arg(long = "ssh-key")]
+    ssh_key: Vec<String>,
+    /// Allocate and attach a static IP so the public address survives stop/reboot.
+    #[arg(long = "static-ip")]
+    static_ip: bool,
 }
 
 #[derive(Args)]
@@ -205,6 +604,15 @@ struct GceUpArgs {
     machine_type: Option<String>,
     #[arg(short = 'c', long = "config")]
     config: Option<String>,
+    /// Path to a cloud-init/user-data file to run at boot.
+    #[arg(long = "user-data")]
+    user_data: Option<String>,
+    /// Literal cloud-init/user-data content to run at boot.
+    #[arg(long = "user-data-inline")]
+    user_data_inline: Option<String>,
+    /// Additional public key file to grant access; may be repeated.
+    #[arg(long = "ssh-key")]
+    ssh_key: Vec<String>,
 }
 
 #[derive(Args)]
@@ -215,6 +623,33 @@ struct DropletUpArgs {
     size: Option<String>,
     #[arg(short = 'c', long = "config")]
     config: Option<String>,
+    /// Path to a cloud-init/user-data file to run at boot.
+    #[arg(long = "user-data")]
+    user_data: Option<String>,
+    /// Literal cloud-init/user-data content to run at boot.
+    #[arg(long = "user-data-inline")]
+    user_data_inline: Option<String>,
+    /// Additional public key file to grant access; may be repeated.
+    #[arg(long = "ssh-key")]
+    ssh_key: Vec<String>,
+    /// Don't return until an SSH-level reachability probe succeeds, not just
+    /// until doctl reports the droplet as active.
+    #[arg(long = "wait-ssh")]
+    wait_ssh: bool,
+    /// Continue a provisioning run that was interrupted, replaying its
+    /// activity journal instead of starting over from scratch.
+    #[arg(long = "resume")]
+    resume: bool,
+}
+
+#[derive(Args)]
+struct OpenstackUpArgs {
+    cluster: String,
+    name: String,
+    #[arg(short = 'F', long = "flavor")]
+    flavor: Option<String>,
+    #[arg(short = 'c', long = "config")]
+    config: Option<String>,
 }
 
 #[derive(Args)]
@@ -231,16 +666,27 @@ struct GceZonesArgs {
     json: bool,
 }
 
+/// The global `config.toml` layer. Layering with the per-cluster
+/// `ClusterConfig` and the `VMCLI_<PROVIDER>_<FIELD>` env vars is handled by
+/// a `figment` provider stack (see `layered_config_figment`): global
+/// `config.toml`, then per-cluster `config.toml`, then `vmcli_env_provider()`,
+/// merged in that precedence order.
 #[derive(Debug, Deserialize, Default, Clone)]
+#[serde(deny_unknown_fields)]
 struct GlobalConfig {
     #[serde(alias = "aws")]
     ec2: Option<AwsConfigSection>,
     lightsail: Option<LightsailConfigSection>,
     gce: Option<GceConfigSection>,
     droplet: Option<DropletConfigSection>,
+    openstack: Option<OpenstackConfigSection>,
+    /// Where to append the JSON-lines audit trail (see `AuditRecord`).
+    /// Defaults to `audit.jsonl` under the config dir when unset.
+    audit_log_path: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Default, Clone)]
+#[serde(deny_unknown_fields)]
 struct ClusterConfig {
     cluster_name: Option<String>,
     #[serde(alias = "aws")]
@@ -248,14 +694,160 @@ struct ClusterConfig {
     lightsail: Option<LightsailConfigSection>,
     gce: Option<GceConfigSection>,
     droplet: Option<DropletConfigSection>,
+    openstack: Option<OpenstackConfigSection>,
+}
+
+/// One `[[firewall]]` entry in a cluster config: a single ingress rule that
+/// `ec2 firewall`/`lightsail firewall` reconcile against the live provider.
+/// A rule either names a `preset` (`ssh`/`http`/`https`, expanded by
+/// `resolve_firewall_rules` into protocol/port) or spells out `port`
+/// (optionally with `to_port` for a range) and `protocol` itself. The source
+/// is a `cidr` or, for EC2, a `source_security_group` naming another group.
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+struct FirewallRule {
+    #[serde(default)]
+    port: u16,
+    to_port: Option<u16>,
+    #[serde(default = "default_firewall_protocol")]
+    protocol: String,
+    #[serde(default = "default_firewall_cidr")]
+    cidr: String,
+    source_security_group: Option<String>,
+    /// A managed prefix list ID (`pl-...`) to use as the source instead of a CIDR or SG.
+    prefix_list: Option<String>,
+    /// Ignore `cidr` and scope this rule to the caller's own public IP (`/32`),
+    /// resolved once per run via an outbound checkip query.
+    #[serde(default)]
+    auto_detect_caller_ip: bool,
+    preset: Option<String>,
+    description: Option<String>,
+}
+
+fn default_firewall_protocol() -> String {
+    "tcp".to_string()
+}
+
+fn default_firewall_cidr() -> String {
+    "0.0.0.0/0".to_string()
+}
+
+fn firewall_rule(port: u16) -> FirewallRule {
+    FirewallRule {
+        port,
+        to_port: None,
+        protocol: default_firewall_protocol(),
+        cidr: default_firewall_cidr(),
+        source_security_group: None,
+        prefix_list: None,
+        auto_detect_caller_ip: false,
+        preset: None,
+        description: None,
+    }
+}
+
+/// Expands `preset` shorthand (`ssh`, `http`, `https`) into concrete
+/// protocol/port/to_port values, so every other consumer of `FirewallRule`
+/// only ever has to deal with fully-resolved rules.
+fn firewall_preset(name: &str) -> Option<(&'static str, u16, u16)> {
+    match name {
+        "ssh" => Some(("tcp", 22, 22)),
+        "http" => Some(("tcp", 80, 80)),
+        "https" => Some(("tcp", 443, 443)),
+        _ => None,
+    }
+}
+
+/// Resolves every rule's `preset` (if set) against `firewall_preset`,
+/// bailing on an unknown name, requires an explicit `port` otherwise, and
+/// expands `auto_detect_caller_ip` rules to the caller's own `/32` so the
+/// config never needs to hardcode a world-open CIDR for admin ports.
+fn resolve_firewall_rules(rules: Vec<FirewallRule>) -> Result<Vec<FirewallRule>> {
+    let mut caller_ip: Option<String> = None;
+    rules
+        .into_iter()
+        .map(|mut rule| {
+            if let Some(preset) = rule.preset.take() {
+                let (protocol, port, to_port) = firewall_preset(&preset).ok_or_else(|| {
+                    anyhow!(
+                        "unknown firewall preset '{}' (known presets: ssh, http, https)",
+                        preset
+                    )
+                })?;
+                rule.protocol = protocol.to_string();
+                rule.port = port;
+                rule.to_port = Some(to_port);
+            } else if rule.port == 0 {
+                bail!("firewall rule must set either `port` or `preset`");
+            }
+            if rule.auto_detect_caller_ip {
+                if rule.source_security_group.is_some() || rule.prefix_list.is_some() {
+                    bail!("firewall rule cannot combine `auto_detect_caller_ip` with `source_security_group` or `prefix_list`");
+                }
+                let ip = match caller_ip.as_ref() {
+                    Some(ip) => ip.clone(),
+                    None => {
+                        let ip = detect_public_ip()?;
+                        caller_ip = Some(ip.clone());
+                        ip
+                    }
+                };
+                rule.cidr = format!("{}/32", ip);
+            }
+            Ok(rule)
+        })
+        .collect()
+}
+
+/// Resolves the caller's public IP via an outbound checkip query, for
+/// scoping `auto_detect_caller_ip` firewall rules to `<ip>/32`.
+fn detect_public_ip() -> Result<String> {
+    let output = Command::new("curl")
+        .args(["-s", "--max-time", "5", "https://checkip.amazonaws.com"])
+        .output()
+        .context("run curl to detect public IP")?;
+    if !output.status.success() {
+        bail!(
+            "failed to detect public IP: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    let ip = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    ip.parse::<IpAddr>()
+        .with_context(|| format!("checkip returned an invalid IP address: '{}'", ip))?;
+    Ok(ip)
+}
+
+/// Ingress rules applied when a cluster config has no `[[firewall]]` entries,
+/// matching vmcli's historical hardcoded EC2 security group ports.
+fn default_ec2_firewall_rules() -> Vec<FirewallRule> {
+    [22, 80, 443, 9090, 9091, 9092]
+        .into_iter()
+        .map(firewall_rule)
+        .collect()
+}
+
+/// Ingress rules applied when a cluster config has no `[[firewall]]` entries,
+/// matching vmcli's historical hardcoded Lightsail public ports.
+fn default_lightsail_firewall_rules() -> Vec<FirewallRule> {
+    [22, 80, 443].into_iter().map(firewall_rule).collect()
 }
 
 #[derive(Debug, Deserialize, Default, Clone)]
+#[serde(deny_unknown_fields)]
 struct AwsConfigSection {
     region: Option<String>,
     ssh_public_key_path: Option<String>,
     default_instance_type: Option<String>,
     ami_id: Option<String>,
+    user_data: Option<String>,
+    #[serde(default)]
+    firewall: Vec<FirewallRule>,
+    vpc_cidr: Option<String>,
+    #[serde(default)]
+    subnet_cidrs: Vec<String>,
+    #[serde(default)]
+    availability_zones: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -265,10 +857,34 @@ struct AwsEffectiveConfig {
     ssh_public_key_path: String,
     default_instance_type: String,
     ami_id: Option<String>,
+    user_data: Option<String>,
+    firewall: Vec<FirewallRule>,
     ssh_config_path: PathBuf,
+    vpc_cidr: String,
+    subnets: Vec<SubnetSpec>,
+}
+
+/// One availability-zone subnet to create/reconcile inside the cluster VPC,
+/// tagged `<cluster>-subnet-<az-suffix>` (e.g. `mycluster-subnet-a`).
+#[derive(Debug, Clone)]
+struct SubnetSpec {
+    availability_zone: String,
+    cidr: String,
+}
+
+impl SubnetSpec {
+    /// The trailing AZ letter (`us-east-1a` -> `a`), used to name the subnet.
+    fn suffix(&self) -> String {
+        self.availability_zone
+            .chars()
+            .last()
+            .map(|c| c.to_string())
+            .unwrap_or_else(|| "a".to_string())
+    }
 }
 
 #[derive(Debug, Deserialize, Default, Clone)]
+#[serde(deny_unknown_fields)]
 struct LightsailConfigSection {
     region: Option<String>,
     ssh_public_key_path: Option<String>,
@@ -276,6 +892,9 @@ struct LightsailConfigSection {
     default_bundle_id: Option<String>,
     blueprint_id: Option<String>,
     key_pair_name: Option<String>,
+    user_data: Option<String>,
+    #[serde(default)]
+    firewall: Vec<FirewallRule>,
 }
 
 #[derive(Debug, Clone)]
@@ -287,10 +906,13 @@ struct LightsailEffectiveConfig {
     default_bundle_id: String,
     blueprint_id: String,
     key_pair_name: Option<String>,
+    user_data: Option<String>,
+    firewall: Vec<FirewallRule>,
     ssh_config_path: PathBuf,
 }
 
 #[derive(Debug, Deserialize, Default, Clone)]
+#[serde(deny_unknown_fields)]
 struct GceConfigSection {
     project: Option<String>,
     zone: Option<String>,
@@ -299,6 +921,7 @@ struct GceConfigSection {
     image_family: Option<String>,
     image_project: Option<String>,
     ssh_user: Option<String>,
+    user_data: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -311,16 +934,19 @@ struct GceEffectiveConfig {
     image_family: String,
     image_project: String,
     ssh_user: String,
+    user_data: Option<String>,
     ssh_config_path: PathBuf,
 }
 
 #[derive(Debug, Deserialize, Default, Clone)]
+#[serde(deny_unknown_fields)]
 struct DropletConfigSection {
     region: Option<String>,
     ssh_public_key_path: Option<String>,
     default_size: Option<String>,
     image: Option<String>,
     ssh_key_fingerprint: Option<String>,
+    user_data: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -331,6 +957,34 @@ struct DropletEffectiveConfig {
     default_size: String,
     image: String,
     ssh_key_fingerprint: Option<String>,
+    user_data: Option<String>,
+    ssh_config_path: PathBuf,
+}
+
+#[derive(Debug, Deserialize, Default, Clone)]
+#[serde(deny_unknown_fields)]
+struct OpenstackConfigSection {
+    auth_url: Option<String>,
+    project: Option<String>,
+    region: Option<String>,
+    default_flavor: Option<String>,
+    image: Option<String>,
+    network: Option<String>,
+    ssh_public_key_path: Option<String>,
+    keypair_name: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct OpenstackEffectiveConfig {
+    cluster_name: String,
+    auth_url: String,
+    project: String,
+    region: String,
+    default_flavor: String,
+    image: String,
+    network: String,
+    ssh_public_key_path: String,
+    keypair_name: String,
     ssh_config_path: PathBuf,
 }
 
@@ -416,6 +1070,18 @@ struct DescribeSecurityGroups {
     security_groups: Vec<SecurityGroup>,
 }
 
+#[derive(Deserialize)]
+struct DescribePlacementGroups {
+    #[serde(rename = "PlacementGroups")]
+    placement_groups: Vec<PlacementGroup>,
+}
+
+#[derive(Deserialize)]
+struct PlacementGroup {
+    #[serde(rename = "GroupName")]
+    group_name: String,
+}
+
 #[derive(Deserialize)]
 struct SecurityGroup {
     #[serde(rename = "GroupId")]
@@ -478,7 +1144,7 @@ struct Reservation {
     instances: Vec<Instance>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 struct Instance {
     #[serde(rename = "InstanceId")]
     instance_id: String,
@@ -498,15 +1164,40 @@ struct Instance {
     security_groups: Option<Vec<InstanceSecurityGroupRef>>,
     #[serde(rename = "Tags")]
     tags: Option<Vec<Tag>>,
+    #[serde(rename = "InstanceLifecycle")]
+    instance_lifecycle: Option<String>,
+    #[serde(rename = "StateReason")]
+    state_reason: Option<InstanceStateReason>,
 }
 
-#[derive(Deserialize)]
+impl Instance {
+    /// `true` when AWS reports this instance as a spot instance rather than on-demand.
+    fn is_spot(&self) -> bool {
+        self.instance_lifecycle.as_deref() == Some("spot")
+    }
+
+    /// `true` when the instance's last state transition was a spot interruption.
+    fn was_spot_interrupted(&self) -> bool {
+        self.state_reason
+            .as_ref()
+            .map(|reason| reason.code.as_str())
+            .is_some_and(|code| code == "Server.SpotInstanceTermination")
+    }
+}
+
+#[derive(Deserialize, Serialize)]
 struct InstanceState {
     #[serde(rename = "Name")]
     name: String,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
+struct InstanceStateReason {
+    #[serde(rename = "Code")]
+    code: String,
+}
+
+#[derive(Deserialize, Serialize)]
 struct Tag {
     #[serde(rename = "Key")]
     key: String,
@@ -514,13 +1205,13 @@ struct Tag {
     value: String,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 struct InstancePlacement {
     #[serde(rename = "AvailabilityZone")]
     availability_zone: Option<String>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 struct InstanceSecurityGroupRef {
     #[serde(rename = "GroupId")]
     group_id: Option<String>,
@@ -554,14 +1245,15 @@ struct EicSendSshPublicKeyResponse {
     success: Option<bool>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 struct Ec2StatusChecks {
     system_status: String,
     instance_status: String,
     checks_pass: Option<bool>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
 enum ProbeOutcome {
     Success,
     Failed,
@@ -578,7 +1270,8 @@ impl ProbeOutcome {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
 enum SgPort22Status {
     OpenWorld,
     Restricted,
@@ -597,7 +1290,7 @@ impl SgPort22Status {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 struct EicProbeResult {
     os_user: String,
     public_ip_present: bool,
@@ -608,7 +1301,22 @@ struct EicProbeResult {
     send_ssh_public_key_reason: Option<String>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Result of directly dialing the instance on port 22, independent of
+/// whatever the AWS control plane (EIC, status checks) reports. A genuine
+/// measurement rather than an inference, so `summarize_health` can tell a
+/// network/route problem (AWS says healthy, but nothing answers on 22) apart
+/// from a local SSH config problem (both the control plane and the socket
+/// agree).
+#[derive(Debug, Clone, Serialize)]
+struct TcpSshProbeResult {
+    tcp_ssh_banner: ProbeOutcome,
+    tcp_ssh_banner_reason: Option<String>,
+    ssh_handshake: ProbeOutcome,
+    ssh_handshake_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
 enum HealthLevel {
     Ok,
     Degraded,
@@ -627,7 +1335,7 @@ impl HealthLevel {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 struct HealthSummary {
     level: HealthLevel,
     ssh_local_problem_likely: Option<bool>,
@@ -639,12 +1347,29 @@ struct InstanceEntry {
     instance_id: String,
     state: String,
     public_ip: Option<String>,
+    region: Option<String>,
+    /// `Some` (with interruption status) for EC2 spot instances; `None` for
+    /// EC2 on-demand instances and for providers that don't have a spot
+    /// market (Lightsail, GCE, Droplet, OpenStack).
+    spot: Option<SpotStatus>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SpotStatus {
+    interrupted: bool,
 }
 
 impl InstanceEntry {
     fn display_name(&self) -> &str {
         self.name.as_deref().unwrap_or("N/A")
     }
+
+    fn lifecycle_label(&self) -> &'static str {
+        match self.spot {
+            Some(_) => "spot",
+            None => "on-demand",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -654,6 +1379,12 @@ struct LightsailInstanceInfo {
     public_ip: Option<String>,
 }
 
+#[derive(Debug, Clone)]
+struct LightsailStaticIpInfo {
+    ip_address: String,
+    is_attached: bool,
+}
+
 #[derive(Debug, Clone)]
 struct GceInstanceInfo {
     name: String,
@@ -672,27 +1403,129 @@ struct DropletInfo {
     region: Option<String>,
 }
 
+#[derive(Debug, Clone)]
+struct OpenstackInstanceInfo {
+    id: String,
+    name: String,
+    state: String,
+    public_ip: Option<String>,
+}
+
 struct AwsCli {
     region: String,
+    backend: AwsBackend,
+    profile: Option<String>,
+    dry_run: bool,
+    plan: Mutex<Vec<PlanEntry>>,
+}
+
+/// A single line of a dry-run plan, rendered Terraform-style (`+`/`~`/`=`).
+struct PlanEntry {
+    change: PlanChange,
+    resource: String,
+    detail: String,
+}
+
+#[derive(PartialEq, Eq)]
+enum PlanChange {
+    Create,
+    Modify,
+    NoChange,
+}
+
+impl PlanChange {
+    fn symbol(&self) -> &'static str {
+        match self {
+            PlanChange::Create => "+",
+            PlanChange::Modify => "~",
+            PlanChange::NoChange => "=",
+        }
+    }
 }
 
 impl AwsCli {
     fn new(region: String) -> Self {
-        Self { region }
+        Self {
+            region,
+            backend: AwsBackend::Cli,
+            profile: None,
+            dry_run: false,
+            plan: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn new_with_backend(region: String, backend: AwsBackend, profile: Option<String>) -> Self {
+        Self {
+            region,
+            backend,
+            profile,
+            dry_run: false,
+            plan: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Records a plan entry in dry-run mode instead of mutating. Returns `true`
+    /// when the caller should skip the real mutating call.
+    fn plan_mutation(&self, change: PlanChange, resource: impl Into<String>, detail: impl Into<String>) -> bool {
+        if !self.dry_run {
+            return false;
+        }
+        self.plan.lock().unwrap().push(PlanEntry {
+            change,
+            resource: resource.into(),
+            detail: detail.into(),
+        });
+        true
+    }
+
+    fn print_plan(&self) {
+        let plan = self.plan.lock().unwrap();
+        let mut adds = 0;
+        let mut changes = 0;
+        for entry in plan.iter() {
+            println!("{} {} ({})", entry.change.symbol(), entry.resource, entry.detail);
+            match entry.change {
+                PlanChange::Create => adds += 1,
+                PlanChange::Modify => changes += 1,
+                PlanChange::NoChange => {}
+            }
+        }
+        if adds == 0 && changes == 0 {
+            println!("no changes; cluster is already up to date");
+        } else {
+            println!("plan: {} to add, {} to change", adds, changes);
+        }
     }
 
     fn run_output(&self, args: &[String]) -> Result<Output> {
         let mut cmd = Command::new("aws");
         cmd.args(args);
         cmd.arg("--region").arg(&self.region);
+        if let Some(profile) = self.profile.as_deref() {
+            cmd.arg("--profile").arg(profile);
+        }
         cmd.env("AWS_PAGER", "");
         let output = cmd.output().context("failed to execute aws CLI")?;
         Ok(output)
     }
 
+    /// Runs `aws <args>`, retrying with exponential backoff + jitter when the
+    /// failure looks like transient throttling or an AWS-side internal error
+    /// (see `is_retryable_aws_error`). Non-retryable failures (bad arguments,
+    /// resource-not-found, permissions) still fail on the first attempt.
     fn run(&self, args: &[String]) -> Result<String> {
-        let output = self.run_output(args)?;
-        if !output.status.success() {
+        let mut delay = AWS_RETRY_BASE_DELAY;
+        for attempt in 1..=AWS_RETRY_MAX_ATTEMPTS {
+            let output = self.run_output(args)?;
+            if output.status.success() {
+                return Ok(String::from_utf8_lossy(&output.stdout).trim().to_string());
+            }
+
             let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
             let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
             let mut message = format!("aws {} failed", args.join(" "));
@@ -702,10 +1535,14 @@ impl AwsCli {
             if !stdout.is_empty() {
                 message.push_str(&format!("\n{}", stdout));
             }
-            bail!(message);
-        }
 
-        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+            if attempt == AWS_RETRY_MAX_ATTEMPTS || !is_retryable_aws_error(&message) {
+                bail!(message);
+            }
+            sleep(delay + jitter(delay));
+            delay = (delay * 2).min(AWS_RETRY_MAX_DELAY);
+        }
+        unreachable!("loop always returns or bails by the final attempt")
     }
 
     fn get_caller_identity(&self) -> Result<CallerIdentity> {
@@ -796,43 +1633,112 @@ impl DoctlCli {
     }
 }
 
-fn main() {
-    if let Err(err) = run() {
-        eprintln!("error: {:#}", err);
-        std::process::exit(1);
-    }
+struct OpenstackCli {
+    auth_url: String,
+    project: String,
+    region: String,
 }
 
-fn run() -> Result<()> {
-    let cli = Cli::parse();
-    let config_root = expand_home_path(&cli.config_dir)?;
-    match cli.command {
-        TopCommand::Ec2(ec2) => match ec2.command {
-            Ec2Command::Init(args) => run_aws_init(args, &config_root),
-            Ec2Command::Up(args) => run_aws_up(args, &config_root),
-            Ec2Command::Status(args) => run_aws_status(args, &config_root),
-            Ec2Command::Health(args) => run_aws_health(args, &config_root),
-            Ec2Command::Reboot(args) => run_aws_reboot(args, &config_root),
-            Ec2Command::Destroy(args) => run_aws_destroy(args, &config_root),
-            Ec2Command::Prune(args) => run_aws_prune(args, &config_root),
-            Ec2Command::Regions(args) => run_ec2_regions(args),
-        },
-        TopCommand::Lightsail(provider) => run_lightsail(provider, &config_root),
-        TopCommand::Gce(provider) => run_gce(provider, &config_root),
-        TopCommand::Droplet(provider) => run_droplet(provider, &config_root),
+impl OpenstackCli {
+    fn new(auth_url: String, project: String, region: String) -> Self {
+        Self {
+            auth_url,
+            project,
+            region,
+        }
     }
-}
 
-fn run_lightsail(args: LightsailArgs, config_root: &Path) -> Result<()> {
-    match args.command {
-        LightsailCommand::Init(args) => run_lightsail_init(args, config_root),
-        LightsailCommand::Up(args) => run_lightsail_up(args, config_root),
-        LightsailCommand::Status(args) => run_lightsail_status(args, config_root),
-        LightsailCommand::Health(args) => run_lightsail_health(args, config_root),
-        LightsailCommand::Reboot(args) => run_lightsail_reboot(args, config_root),
-        LightsailCommand::Destroy(args) => run_lightsail_destroy(args, config_root),
-        LightsailCommand::Prune(args) => run_lightsail_prune(args, config_root),
+    fn run_output(&self, args: &[String]) -> Result<Output> {
+        let mut cmd = Command::new("openstack");
+        cmd.args(args);
+        cmd.arg("--os-auth-url").arg(&self.auth_url);
+        cmd.arg("--os-project-name").arg(&self.project);
+        cmd.arg("--os-region-name").arg(&self.region);
+        let output = cmd.output().context("failed to execute openstack CLI")?;
+        Ok(output)
+    }
+
+    fn run(&self, args: &[String]) -> Result<String> {
+        let output = self.run_output(args)?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            let mut message = format!("openstack {} failed", args.join(" "));
+            if !stderr.is_empty() {
+                message.push_str(&format!(": {}", stderr));
+            }
+            if !stdout.is_empty() {
+                message.push_str(&format!("\n{}", stdout));
+            }
+            bail!(message);
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    fn run_json(&self, args: &[String]) -> Result<serde_json::Value> {
+        let stdout = self.run(args)?;
+        serde_json::from_str(&stdout).context("parse openstack json output")
+    }
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("error: {:#}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<()> {
+    let cli = Cli::parse();
+    let config_root = expand_home_path(&cli.config_dir)?;
+    let backend = cli.backend;
+    let profile = cli.profile.clone();
+    match cli.command {
+        TopCommand::Ec2(ec2) => match ec2.command {
+            Ec2Command::Init(args) => run_aws_init(args, &config_root),
+            Ec2Command::Up(args) => run_aws_up(args, &config_root, backend, profile),
+            Ec2Command::ScaleUp(args) => run_aws_scale_up(args, &config_root, backend, profile),
+            Ec2Command::Status(args) => run_aws_status(args, &config_root, backend, profile),
+            Ec2Command::Health(args) => run_aws_health(args, &config_root, backend, profile),
+            Ec2Command::Exec(args) => run_aws_exec(args, &config_root, backend, profile),
+            Ec2Command::Ssh(args) => run_aws_ssh(args, &config_root, backend, profile),
+            Ec2Command::Reboot(args) => run_aws_reboot(args, &config_root, backend, profile),
+            Ec2Command::Destroy(args) => run_aws_destroy(args, &config_root, backend, profile),
+            Ec2Command::Prune(args) => run_aws_prune(args, &config_root, backend, profile),
+            Ec2Command::Regions(args) => run_ec2_regions(args),
+            Ec2Command::Config(args) => run_aws_config(args, &config_root, profile),
+            Ec2Command::Reap(args) => run_aws_reap(args, &config_root, backend, profile),
+            Ec2Command::Firewall(args) => run_aws_firewall(args, &config_root, backend, profile),
+            Ec2Command::Resources(args) => run_aws_resources(args, &config_root, backend, profile),
+        },
+        TopCommand::Lightsail(provider) => run_lightsail(provider, &config_root),
+        TopCommand::Gce(provider) => run_gce(provider, &config_root),
+        TopCommand::Droplet(provider) => run_droplet(provider, &config_root),
+        TopCommand::Openstack(provider) => run_openstack(provider, &config_root),
+        TopCommand::Status(args) => run_status_all(args, &config_root, backend),
+        TopCommand::Ls(mut args) => {
+            args.all = true;
+            run_status_all(args, &config_root, backend)
+        }
+        TopCommand::Config(args) => match args.command {
+            ConfigCommand::Validate(args) => run_config_validate(args, &config_root, profile),
+        },
+    }
+}
+
+fn run_lightsail(args: LightsailArgs, config_root: &Path) -> Result<()> {
+    match args.command {
+        LightsailCommand::Init(args) => run_lightsail_init(args, config_root),
+        LightsailCommand::Up(args) => run_lightsail_up(args, config_root),
+        LightsailCommand::Status(args) => run_lightsail_status(args, config_root),
+        LightsailCommand::Health(args) => run_lightsail_health(args, config_root),
+        LightsailCommand::Exec(args) => run_lightsail_exec(args, config_root),
+        LightsailCommand::Reboot(args) => run_lightsail_reboot(args, config_root),
+        LightsailCommand::Destroy(args) => run_lightsail_destroy(args, config_root),
+        LightsailCommand::Prune(args) => run_lightsail_prune(args, config_root),
         LightsailCommand::Regions(args) => run_lightsail_regions(args),
+        LightsailCommand::Config(args) => run_lightsail_config(args, config_root),
+        LightsailCommand::Firewall(args) => run_lightsail_firewall(args, config_root),
     }
 }
 
@@ -842,11 +1748,13 @@ fn run_gce(args: GceArgs, config_root: &Path) -> Result<()> {
         GceCommand::Up(args) => run_gce_up(args, config_root),
         GceCommand::Status(args) => run_gce_status(args, config_root),
         GceCommand::Health(args) => run_gce_health(args, config_root),
+        GceCommand::Exec(args) => run_gce_exec(args, config_root),
         GceCommand::Reboot(args) => run_gce_reboot(args, config_root),
         GceCommand::Destroy(args) => run_gce_destroy(args, config_root),
         GceCommand::Prune(args) => run_gce_prune(args, config_root),
         GceCommand::Regions(args) => run_gce_regions(args),
         GceCommand::Zones(args) => run_gce_zones(args),
+        GceCommand::Config(args) => run_gce_config(args, config_root),
     }
 }
 
@@ -856,10 +1764,444 @@ fn run_droplet(args: DropletArgs, config_root: &Path) -> Result<()> {
         DropletCommand::Up(args) => run_droplet_up(args, config_root),
         DropletCommand::Status(args) => run_droplet_status(args, config_root),
         DropletCommand::Health(args) => run_droplet_health(args, config_root),
+        DropletCommand::Exec(args) => run_droplet_exec(args, config_root),
         DropletCommand::Reboot(args) => run_droplet_reboot(args, config_root),
         DropletCommand::Destroy(args) => run_droplet_destroy(args, config_root),
         DropletCommand::Prune(args) => run_droplet_prune(args, config_root),
         DropletCommand::Regions(args) => run_droplet_regions(args),
+        DropletCommand::Config(args) => run_droplet_config(args, config_root),
+    }
+}
+
+fn run_openstack(args: OpenstackArgs, config_root: &Path) -> Result<()> {
+    match args.command {
+        OpenstackCommand::Init(args) => run_openstack_init(args, config_root),
+        OpenstackCommand::Up(args) => run_openstack_up(args, config_root),
+        OpenstackCommand::Status(args) => run_openstack_status(args, config_root),
+        OpenstackCommand::Health(args) => run_openstack_health(args, config_root),
+        OpenstackCommand::Exec(args) => run_openstack_exec(args, config_root),
+        OpenstackCommand::Reboot(args) => run_openstack_reboot(args, config_root),
+        OpenstackCommand::Destroy(args) => run_openstack_destroy(args, config_root),
+        OpenstackCommand::Prune(args) => run_openstack_prune(args, config_root),
+        OpenstackCommand::Regions(args) => run_openstack_regions(args),
+        OpenstackCommand::Config(args) => run_openstack_config(args, config_root),
+    }
+}
+
+/// One provider/cluster's contribution to `vmcli status --all`.
+struct ClusterStatusJob {
+    provider: &'static str,
+    cluster: String,
+}
+
+fn run_status_all(args: GlobalStatusArgs, config_root: &Path, backend: AwsBackend) -> Result<()> {
+    if !args.all {
+        bail!("vmcli status currently only supports --all; pass --all to query every configured provider/cluster");
+    }
+
+    let providers = [
+        EC2_PROVIDER,
+        LIGHTSAIL_PROVIDER,
+        GCE_PROVIDER,
+        DROPLET_PROVIDER,
+        OPENSTACK_PROVIDER,
+    ];
+    if let Some(provider) = args.provider.as_deref() {
+        if !providers.contains(&provider) {
+            bail!(
+                "unknown provider '{}';{} expected one of: {}",
+                provider,
+                did_you_mean(provider, &providers),
+                providers.join(", ")
+            );
+        }
+    }
+
+    let mut jobs = Vec::new();
+    for provider in providers {
+        if args.provider.as_deref().is_some_and(|wanted| wanted != provider) {
+            continue;
+        }
+        for cluster in discover_configured_clusters(config_root, provider) {
+            if args.cluster.as_deref().is_some_and(|wanted| wanted != cluster) {
+                continue;
+            }
+            jobs.push(ClusterStatusJob { provider, cluster });
+        }
+    }
+
+    if jobs.is_empty() {
+        if args.output == GlobalStatusOutput::Json {
+            println!("[]");
+        } else {
+            println!("no configured clusters found under {}", config_root.display());
+        }
+        return Ok(());
+    }
+
+    let concurrency = args.concurrency.unwrap_or_else(default_concurrency);
+    let mut results = run_with_concurrency(jobs, concurrency, |job| {
+        let outcome = fetch_cluster_status_entries(config_root, backend, job.provider, &job.cluster);
+        (job.provider, job.cluster, outcome)
+    });
+    results.sort_by(|a, b| (a.0, &a.1).cmp(&(b.0, &b.1)));
+
+    if args.output == GlobalStatusOutput::Json {
+        let mut records = Vec::new();
+        for (provider, cluster, outcome) in &results {
+            match outcome {
+                Ok(entries) => {
+                    for entry in entries {
+                        records.push(serde_json::json!({
+                            "provider": provider,
+                            "cluster": cluster,
+                            "name": entry.display_name(),
+                            "instance_id": entry.instance_id,
+                            "state": entry.state,
+                            "public_ip": entry.public_ip,
+                            "region": entry.region,
+                        }));
+                    }
+                }
+                Err(err) => {
+                    records.push(serde_json::json!({
+                        "provider": provider,
+                        "cluster": cluster,
+                        "error": err.to_string(),
+                    }));
+                }
+            }
+        }
+        println!("{}", serde_json::to_string_pretty(&records)?);
+        return Ok(());
+    }
+
+    for (provider, cluster, outcome) in results {
+        match outcome {
+            Ok(mut entries) => {
+                if entries.is_empty() {
+                    println!("provider={} cluster={} (no instances)", provider, cluster);
+                }
+                entries.sort_by(|a, b| a.display_name().cmp(&b.display_name()));
+                for entry in &entries {
+                    let public_ip = entry.public_ip.as_deref().unwrap_or("N/A");
+                    let region = entry.region.as_deref().unwrap_or("N/A");
+                    println!(
+                        "provider={} cluster={} name={} instance-id={} state={} public-ip={} region={}",
+                        provider,
+                        cluster,
+                        entry.display_name(),
+                        entry.instance_id,
+                        entry.state,
+                        public_ip,
+                        region
+                    );
+                }
+            }
+            Err(err) => {
+                println!("provider={} cluster={} error={}", provider, cluster, err);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates every configured cluster's config across all merge layers
+/// (global + per-cluster config.toml, `merge_*_section`, env overrides) and
+/// the referenced `ssh_public_key_path`, without touching any cloud API.
+/// Unlike `load_*_config`, which bails on the first problem, this collects
+/// every cluster's validation outcome so CI can see the full picture at once.
+fn run_config_validate(
+    args: ConfigValidateArgs,
+    config_root: &Path,
+    profile: Option<String>,
+) -> Result<()> {
+    let providers = [
+        EC2_PROVIDER,
+        LIGHTSAIL_PROVIDER,
+        GCE_PROVIDER,
+        DROPLET_PROVIDER,
+        OPENSTACK_PROVIDER,
+    ];
+    if let Some(provider) = args.provider.as_deref() {
+        if !providers.contains(&provider) {
+            bail!(
+                "unknown provider '{}';{} expected one of: {}",
+                provider,
+                did_you_mean(provider, &providers),
+                providers.join(", ")
+            );
+        }
+    }
+
+    let mut jobs = Vec::new();
+    for provider in providers {
+        if args.provider.as_deref().is_some_and(|wanted| wanted != provider) {
+            continue;
+        }
+        for cluster in discover_configured_clusters(config_root, provider) {
+            if args.cluster.as_deref().is_some_and(|wanted| wanted != cluster) {
+                continue;
+            }
+            jobs.push((provider, cluster));
+        }
+    }
+
+    if jobs.is_empty() {
+        if args.output == GlobalStatusOutput::Json {
+            println!("[]");
+        } else {
+            println!("no configured clusters found under {}", config_root.display());
+        }
+        return Ok(());
+    }
+
+    let results: Vec<(&str, String, Result<()>)> = jobs
+        .into_iter()
+        .map(|(provider, cluster)| {
+            let outcome = validate_cluster_config(config_root, provider, &cluster, profile.as_deref());
+            (provider, cluster, outcome)
+        })
+        .collect();
+
+    let error_count = results.iter().filter(|(_, _, outcome)| outcome.is_err()).count();
+
+    if args.output == GlobalStatusOutput::Json {
+        let records: Vec<_> = results
+            .iter()
+            .map(|(provider, cluster, outcome)| match outcome {
+                Ok(()) => serde_json::json!({
+                    "provider": provider,
+                    "cluster": cluster,
+                    "status": "ok",
+                }),
+                Err(err) => serde_json::json!({
+                    "provider": provider,
+                    "cluster": cluster,
+                    "status": "error",
+                    "error": err.to_string(),
+                }),
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&records)?);
+    } else {
+        for (provider, cluster, outcome) in &results {
+            match outcome {
+                Ok(()) => println!("provider={} cluster={} status=ok", provider, cluster),
+                Err(err) => println!(
+                    "provider={} cluster={} status=error error={}",
+                    provider, cluster, err
+                ),
+            }
+        }
+        println!(
+            "validated {} cluster(s), {} error(s)",
+            results.len(),
+            error_count
+        );
+    }
+
+    if error_count > 0 {
+        bail!("config validation failed for {} cluster(s)", error_count);
+    }
+    Ok(())
+}
+
+/// Loads one cluster's effective config for `provider` (exercising the same
+/// `load_*_config`/`merge_*_section` path the real commands use) and checks
+/// that `ssh_public_key_path` actually points at a readable file.
+fn validate_cluster_config(
+    config_root: &Path,
+    provider: &str,
+    cluster: &str,
+    profile: Option<&str>,
+) -> Result<()> {
+    let ssh_public_key_path = match provider {
+        EC2_PROVIDER => load_aws_config(config_root, cluster, None, profile)?.ssh_public_key_path,
+        LIGHTSAIL_PROVIDER => load_lightsail_config(config_root, cluster, None)?.ssh_public_key_path,
+        GCE_PROVIDER => load_gce_config(config_root, cluster, None)?.ssh_public_key_path,
+        DROPLET_PROVIDER => load_droplet_config(config_root, cluster, None)?.ssh_public_key_path,
+        OPENSTACK_PROVIDER => load_openstack_config(config_root, cluster, None)?.ssh_public_key_path,
+        other => bail!("unknown provider '{}'", other),
+    };
+
+    let expanded = expand_home_path(&ssh_public_key_path)?;
+    if !expanded.exists() {
+        bail!(
+            "ssh_public_key_path '{}' does not exist",
+            expanded.display()
+        );
+    }
+    fs::read(&expanded)
+        .with_context(|| format!("ssh_public_key_path '{}' is not readable", expanded.display()))?;
+
+    Ok(())
+}
+
+/// Finds the candidate closest to `input` by Levenshtein edit distance, for
+/// "did you mean" suggestions on a mistyped cluster or provider name. Returns
+/// `None` if nothing is close enough (distance > `max(2, input.len() / 3)`)
+/// so unrelated names are never proposed.
+fn closest_match<'a, S: AsRef<str>>(input: &str, candidates: &'a [S]) -> Option<&'a str> {
+    let max_distance = (input.len() / 3).max(2);
+    candidates
+        .iter()
+        .map(|candidate| (candidate.as_ref(), levenshtein_distance(input, candidate.as_ref())))
+        .filter(|(_, distance)| *distance <= max_distance)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| candidate)
+}
+
+/// Standard two-row dynamic-programming Levenshtein edit distance.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0usize; b.len() + 1];
+    for i in 1..=a.len() {
+        cur[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            cur[j] = (prev[j] + 1).min(cur[j - 1] + 1).min(prev[j - 1] + cost);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
+
+/// Renders a `closest_match` hit as a trailing " did you mean 'x'?" clause,
+/// or an empty string when nothing was close enough to suggest.
+fn did_you_mean<S: AsRef<str>>(input: &str, candidates: &[S]) -> String {
+    closest_match(input, candidates)
+        .map(|candidate| format!(" did you mean '{}'?", candidate))
+        .unwrap_or_default()
+}
+
+/// Lists cluster names configured under `config_root/<provider>/*` (directories
+/// containing a `config.toml`), sorted for stable output.
+fn discover_configured_clusters(config_root: &Path, provider: &str) -> Vec<String> {
+    let provider_dir = config_root.join(provider);
+    let mut clusters = Vec::new();
+    if let Ok(read_dir) = fs::read_dir(&provider_dir) {
+        for entry in read_dir.flatten() {
+            let path = entry.path();
+            if path.is_dir() && path.join(CONFIG_FILE_NAME).exists() {
+                if let Some(name) = path.file_name().and_then(|value| value.to_str()) {
+                    clusters.push(name.to_string());
+                }
+            }
+        }
+    }
+    clusters.sort();
+    clusters
+}
+
+/// Fetches the instance list for one provider/cluster, used by `vmcli status
+/// --all`. Errors (missing CLI, unreachable API, bad config) are returned
+/// rather than propagated, so one broken cluster doesn't hide the rest.
+fn fetch_cluster_status_entries(
+    config_root: &Path,
+    backend: AwsBackend,
+    provider: &str,
+    cluster: &str,
+) -> Result<Vec<InstanceEntry>> {
+    match provider {
+        EC2_PROVIDER => {
+            ensure_no_profile_env(None)?;
+            check_aws_cli()?;
+            let config = load_aws_config(config_root, cluster, None, None)?;
+            let aws = AwsCli::new_with_backend(config.region.clone(), backend, None);
+            let vpc_id = find_vpc(&aws, &config.cluster_name)?;
+            let instances = match vpc_id.as_ref() {
+                Some(vpc_id) => describe_instances_by_vpc(&aws, vpc_id)?,
+                None => Vec::new(),
+            };
+            Ok(instances
+                .into_iter()
+                .map(|instance| InstanceEntry {
+                    name: tag_value(&instance.tags, "Name"),
+                    instance_id: instance.instance_id.clone(),
+                    state: instance.state.name.clone(),
+                    public_ip: instance.public_ip.clone(),
+                    region: Some(config.region.clone()),
+                    spot: instance.is_spot().then_some(SpotStatus {
+                        interrupted: instance.was_spot_interrupted(),
+                    }),
+                })
+                .collect())
+        }
+        LIGHTSAIL_PROVIDER => {
+            ensure_no_profile_env(None)?;
+            check_aws_cli()?;
+            let config = load_lightsail_config(config_root, cluster, None)?;
+            let aws = AwsCli::new(config.region.clone());
+            let entries = lightsail_list_cluster_instances(&aws, &config.cluster_name)?;
+            Ok(entries
+                .into_iter()
+                .map(|entry| InstanceEntry {
+                    name: Some(entry.name.clone()),
+                    instance_id: entry.name,
+                    state: entry.state,
+                    public_ip: entry.public_ip,
+                    region: Some(config.region.clone()),
+                    spot: None,
+                })
+                .collect())
+        }
+        GCE_PROVIDER => {
+            check_gcloud_cli()?;
+            let config = load_gce_config(config_root, cluster, None)?;
+            let gcloud = GcloudCli::new(config.project.clone());
+            let instances = gce_list_cluster_instances(&gcloud, &config.cluster_name)?;
+            Ok(instances
+                .into_iter()
+                .map(|instance| InstanceEntry {
+                    name: Some(instance.name.clone()),
+                    instance_id: instance.instance_id,
+                    state: instance.state,
+                    public_ip: instance.public_ip,
+                    region: instance.zone.clone(),
+                    spot: None,
+                })
+                .collect())
+        }
+        DROPLET_PROVIDER => {
+            check_doctl_cli()?;
+            let config = load_droplet_config(config_root, cluster, None)?;
+            let doctl = DoctlCli::new();
+            let droplets = droplet_list_cluster_instances(&doctl, &config.cluster_name)?;
+            Ok(droplets
+                .into_iter()
+                .map(|droplet| InstanceEntry {
+                    name: Some(droplet.name.clone()),
+                    instance_id: droplet.id.to_string(),
+                    state: droplet.state,
+                    public_ip: droplet.public_ip,
+                    region: Some(config.region.clone()),
+                    spot: None,
+                })
+                .collect())
+        }
+        OPENSTACK_PROVIDER => {
+            check_openstack_cli()?;
+            let config = load_openstack_config(config_root, cluster, None)?;
+            let openstack =
+                OpenstackCli::new(config.auth_url.clone(), config.project.clone(), config.region.clone());
+            let instances = openstack_list_cluster_instances(&openstack, &config.cluster_name)?;
+            Ok(instances
+                .into_iter()
+                .map(|instance| InstanceEntry {
+                    name: Some(instance.name.clone()),
+                    instance_id: instance.id,
+                    state: instance.state,
+                    public_ip: instance.public_ip,
+                    region: Some(config.region.clone()),
+                    spot: None,
+                })
+                .collect())
+        }
+        other => bail!("unknown provider '{}'", other),
     }
 }
 
@@ -876,7 +2218,7 @@ fn aws_metadata_region() -> String {
 }
 
 fn run_ec2_regions(args: ListRegionsArgs) -> Result<()> {
-    ensure_no_profile_env()?;
+    ensure_no_profile_env(None)?;
     check_aws_cli()?;
     let aws = AwsCli::new(aws_metadata_region());
     let query_args = aws_args(&[
@@ -919,8 +2261,65 @@ fn run_ec2_regions(args: ListRegionsArgs) -> Result<()> {
     Ok(())
 }
 
+/// Best-effort region listing for `ec2 init --wizard`; returns an empty list
+/// (rather than an error) if the `aws` CLI is missing or unreachable, since
+/// the wizard only uses this to hint at valid values.
+fn ec2_region_choices() -> Vec<String> {
+    (|| -> Result<Vec<String>> {
+        ensure_no_profile_env(None)?;
+        check_aws_cli()?;
+        let aws = AwsCli::new(aws_metadata_region());
+        let query_args = aws_args(&[
+            "ec2",
+            "describe-regions",
+            "--all-regions",
+            "--output",
+            "json",
+        ]);
+        let output = aws.run(&query_args)?;
+        let payload: serde_json::Value =
+            serde_json::from_str(&output).context("parse ec2 describe-regions")?;
+        let mut regions = payload
+            .get("Regions")
+            .and_then(|value| value.as_array())
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|region| region.get("RegionName")?.as_str().map(|s| s.to_string()))
+            .collect::<Vec<_>>();
+        regions.sort();
+        Ok(regions)
+    })()
+    .unwrap_or_default()
+}
+
+/// Best-effort region listing for `lightsail init --wizard`; see
+/// `ec2_region_choices` for the empty-on-failure rationale.
+fn lightsail_region_choices() -> Vec<String> {
+    (|| -> Result<Vec<String>> {
+        ensure_no_profile_env(None)?;
+        check_aws_cli()?;
+        let aws = AwsCli::new(aws_metadata_region());
+        let query_args = aws_args(&["lightsail", "get-regions", "--output", "json"]);
+        let output = aws.run(&query_args)?;
+        let payload: serde_json::Value =
+            serde_json::from_str(&output).context("parse lightsail get-regions")?;
+        let mut regions = payload
+            .get("regions")
+            .and_then(|value| value.as_array())
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|region| region.get("name")?.as_str().map(|s| s.to_string()))
+            .collect::<Vec<_>>();
+        regions.sort();
+        Ok(regions)
+    })()
+    .unwrap_or_default()
+}
+
 fn run_lightsail_regions(args: ListRegionsArgs) -> Result<()> {
-    ensure_no_profile_env()?;
+    ensure_no_profile_env(None)?;
     check_aws_cli()?;
     let aws = AwsCli::new(aws_metadata_region());
     let query_args = aws_args(&[
@@ -1069,6 +2468,25 @@ fn run_gce_zones(args: GceZonesArgs) -> Result<()> {
     Ok(())
 }
 
+/// Best-effort zone listing for `gce init --wizard`; see `ec2_region_choices`
+/// for the empty-on-failure rationale.
+fn gce_zone_choices() -> Vec<String> {
+    (|| -> Result<Vec<String>> {
+        check_gcloud_cli()?;
+        let payload = run_gcloud_global_json(&["compute", "zones", "list", "--format", "json"])?;
+        let mut zones = payload
+            .as_array()
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|zone| zone.get("name")?.as_str().map(|s| s.to_string()))
+            .collect::<Vec<_>>();
+        zones.sort();
+        Ok(zones)
+    })()
+    .unwrap_or_default()
+}
+
 fn run_droplet_regions(args: ListRegionsArgs) -> Result<()> {
     check_doctl_cli()?;
     let doctl = DoctlCli::new();
@@ -1106,38 +2524,257 @@ fn run_droplet_regions(args: ListRegionsArgs) -> Result<()> {
     Ok(())
 }
 
-fn run_aws_up(args: Ec2UpArgs, config_root: &Path) -> Result<()> {
+/// Best-effort region listing for `droplet init --wizard`; see
+/// `ec2_region_choices` for the empty-on-failure rationale.
+fn droplet_region_choices() -> Vec<String> {
+    (|| -> Result<Vec<String>> {
+        check_doctl_cli()?;
+        let doctl = DoctlCli::new();
+        let payload = doctl.run_json(&[
+            "compute".to_string(),
+            "region".to_string(),
+            "list".to_string(),
+            "--output".to_string(),
+            "json".to_string(),
+        ])?;
+        let mut regions = payload
+            .as_array()
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|region| region.get("slug")?.as_str().map(|s| s.to_string()))
+            .collect::<Vec<_>>();
+        regions.sort();
+        Ok(regions)
+    })()
+    .unwrap_or_default()
+}
+
+/// Live size-slug catalog for `droplet init --interactive`; see
+/// `droplet_region_choices` for the empty-on-failure rationale.
+fn droplet_size_choices() -> Vec<String> {
+    (|| -> Result<Vec<String>> {
+        check_doctl_cli()?;
+        let doctl = DoctlCli::new();
+        let payload = doctl.run_json(&[
+            "compute".to_string(),
+            "size".to_string(),
+            "list".to_string(),
+            "--output".to_string(),
+            "json".to_string(),
+        ])?;
+        let mut sizes = payload
+            .as_array()
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|size| size.get("slug")?.as_str().map(|s| s.to_string()))
+            .collect::<Vec<_>>();
+        sizes.sort();
+        Ok(sizes)
+    })()
+    .unwrap_or_default()
+}
+
+/// Live public-image-slug catalog for `droplet init --interactive`; see
+/// `droplet_region_choices` for the empty-on-failure rationale.
+fn droplet_image_choices() -> Vec<String> {
+    (|| -> Result<Vec<String>> {
+        check_doctl_cli()?;
+        let doctl = DoctlCli::new();
+        let payload = doctl.run_json(&[
+            "compute".to_string(),
+            "image".to_string(),
+            "list".to_string(),
+            "--public".to_string(),
+            "--output".to_string(),
+            "json".to_string(),
+        ])?;
+        let mut images = payload
+            .as_array()
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|image| image.get("slug")?.as_str().map(|s| s.to_string()))
+            .collect::<Vec<_>>();
+        images.sort();
+        Ok(images)
+    })()
+    .unwrap_or_default()
+}
+
+fn run_openstack_global_json(args: &[&str]) -> Result<serde_json::Value> {
+    let mut cmd = Command::new("openstack");
+    cmd.args(args);
+    let output = cmd.output().context("failed to execute openstack CLI")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        let mut message = format!("openstack {} failed", args.join(" "));
+        if !stderr.is_empty() {
+            message.push_str(&format!(": {}", stderr));
+        }
+        if !stdout.is_empty() {
+            message.push_str(&format!("\n{}", stdout));
+        }
+        bail!(message);
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    serde_json::from_str(&stdout).context("parse openstack json output")
+}
+
+fn run_openstack_regions(args: ListRegionsArgs) -> Result<()> {
+    check_openstack_cli()?;
+    let payload = run_openstack_global_json(&["region", "list", "-f", "json"])?;
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&payload)?);
+        return Ok(());
+    }
+
+    let mut regions = payload
+        .as_array()
+        .cloned()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|region| region.get("Region")?.as_str().map(|value| value.to_string()))
+        .collect::<Vec<_>>();
+    regions.sort();
+    for region in regions {
+        println!("region={}", region);
+    }
+    Ok(())
+}
+
+/// Best-effort region listing for `openstack init --wizard`; see
+/// `ec2_region_choices` for the empty-on-failure rationale.
+fn openstack_region_choices() -> Vec<String> {
+    (|| -> Result<Vec<String>> {
+        check_openstack_cli()?;
+        let payload = run_openstack_global_json(&["region", "list", "-f", "json"])?;
+        let mut regions = payload
+            .as_array()
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|region| region.get("Region")?.as_str().map(|v| v.to_string()))
+            .collect::<Vec<_>>();
+        regions.sort();
+        Ok(regions)
+    })()
+    .unwrap_or_default()
+}
+
+fn run_aws_up(
+    args: Ec2UpArgs,
+    config_root: &Path,
+    backend: AwsBackend,
+    profile: Option<String>,
+) -> Result<()> {
     ensure_vmcli_ssh_keypair(config_root)?;
-    ensure_no_profile_env()?;
+    ensure_no_profile_env(profile.as_deref())?;
     check_aws_cli()?;
-    let config = load_aws_config(config_root, &args.cluster, args.config.as_deref())?;
+    let config = load_aws_config(config_root, &args.cluster, args.config.as_deref(), profile.as_deref())?;
     let region = config.region.clone();
-    let aws = AwsCli::new(region);
+    let aws = AwsCli::new_with_backend(region, backend, profile.clone()).with_dry_run(args.dry_run);
     print_banner(&aws)?;
 
     ensure_no_duplicate_instance(&aws, &config.cluster_name, &args.name)?;
 
     let vpc_id = ensure_vpc(&aws, &config)?;
-    let subnet_id = ensure_subnet(&aws, &config, &vpc_id)?;
+    let subnet_ids = ensure_subnet(&aws, &config, &vpc_id)?;
+    let primary_subnet_id = subnet_ids
+        .first()
+        .cloned()
+        .ok_or_else(|| anyhow!("no availability zones configured for cluster {}", config.cluster_name))?;
     let igw_id = ensure_internet_gateway(&aws, &config, &vpc_id)?;
-    ensure_route_table(&aws, &config, &vpc_id, &subnet_id, &igw_id)?;
+    ensure_route_table(&aws, &config, &vpc_id, &subnet_ids, &igw_id)?;
     let sg_id = ensure_security_group(&aws, &config, &vpc_id)?;
     let key_name = ensure_key_pair(&aws, &config)?;
     let ami_id = resolve_ami_id(&aws, &config)?;
     let instance_type = resolve_instance_type(&config, args.instance_type);
+    let user_data = resolve_user_data(args.user_data.as_deref(), args.user_data_inline.as_deref())?
+        .or_else(|| config.user_data.clone());
+    let user_data = merge_ssh_authorized_keys(user_data, &args.ssh_key)?;
+
+    if args.spot_max_price.is_some() && !args.spot {
+        bail!("--spot-max-price requires --spot");
+    }
+    if args.spot_persistent && !args.spot {
+        bail!("--spot-persistent requires --spot");
+    }
+    if args.spot_fallback_on_demand && !args.spot {
+        bail!("--spot-fallback-on-demand requires --spot");
+    }
+    let spot = args.spot.then_some(SpotOptions {
+        max_price: args.spot_max_price.as_deref(),
+        interruption_behavior: args.spot_interruption_behavior,
+        persistent: args.spot_persistent,
+    });
+    let expire_at = match args.lifetime.as_deref() {
+        Some(lifetime) => Some(unix_timestamp_now()? + parse_lifetime_secs(lifetime)?),
+        None => None,
+    };
+
+    if aws.plan_mutation(
+        PlanChange::Create,
+        "ec2 instance",
+        format!(
+            "name={}, ami={}, instance-type={}",
+            args.name, ami_id, instance_type
+        ),
+    ) {
+        aws.print_plan();
+        return Ok(());
+    }
 
-    let instance_id = launch_instance(
+    let is_spot_request = spot.is_some();
+    let launch_result = launch_instance(
         &aws,
         &config,
+        config_root,
         &args.name,
         &ami_id,
         &instance_type,
-        &subnet_id,
+        &primary_subnet_id,
         &sg_id,
         &key_name,
-    )?;
+        user_data.as_deref(),
+        spot,
+        expire_at,
+        None,
+    );
+    let instance_id = match launch_result {
+        Ok(instance_id) => instance_id,
+        Err(err)
+            if is_spot_request
+                && args.spot_fallback_on_demand
+                && is_spot_capacity_error(&format!("{:#}", err)) =>
+        {
+            eprintln!(
+                "spot request for {} failed ({:#}); falling back to on-demand launch",
+                args.name, err
+            );
+            launch_instance(
+                &aws,
+                &config,
+                config_root,
+                &args.name,
+                &ami_id,
+                &instance_type,
+                &primary_subnet_id,
+                &sg_id,
+                &key_name,
+                user_data.as_deref(),
+                None,
+                expire_at,
+                None,
+            )?
+        }
+        Err(err) => return Err(err),
+    };
 
     wait_for_instance_running(&aws, &instance_id)?;
+    wait_for_instance_status_checks_ok(&aws, &instance_id, "running", INSTANCE_STATUS_CHECKS_TIMEOUT)?;
     let public_ip = fetch_instance_public_ip(&aws, &instance_id)?;
     let public_ip_display = public_ip.unwrap_or_else(|| "N/A".to_string());
 
@@ -1150,61 +2787,506 @@ fn run_aws_up(args: Ec2UpArgs, config_root: &Path) -> Result<()> {
     Ok(())
 }
 
-fn run_aws_reboot(args: RebootArgs, config_root: &Path) -> Result<()> {
-    ensure_no_profile_env()?;
+/// Provisions `args.count` nodes named `<name-prefix>-0`, `<name-prefix>-1`,
+/// ... against a single shared VPC/subnet/security-group/key-pair (created
+/// once up front), fanning out the per-node `run-instances` calls with
+/// bounded concurrency via `run_with_concurrency`. One node failing (a
+/// duplicate name, a capacity error) doesn't abort the rest of the batch;
+/// failures are reported per-node alongside the successes.
+fn run_aws_scale_up(
+    args: Ec2ScaleUpArgs,
+    config_root: &Path,
+    backend: AwsBackend,
+    profile: Option<String>,
+) -> Result<()> {
+    if args.count == 0 {
+        bail!("--count must be at least 1");
+    }
+    if args.placement_group_partitions.is_some()
+        && args.placement_group_strategy != Some(PlacementGroupStrategy::Partition)
+    {
+        bail!("--placement-group-partitions requires --placement-group-strategy partition");
+    }
+
+    ensure_vmcli_ssh_keypair(config_root)?;
+    ensure_no_profile_env(profile.as_deref())?;
     check_aws_cli()?;
-    let config = load_aws_config(config_root, &args.cluster, args.config.as_deref())?;
+    let config = load_aws_config(config_root, &args.cluster, args.config.as_deref(), profile.as_deref())?;
     let region = config.region.clone();
-    let aws = AwsCli::new(region);
+    let aws = AwsCli::new_with_backend(region, backend, profile.clone()).with_dry_run(args.dry_run);
     print_banner(&aws)?;
 
-    let instance = find_instance_by_name(&aws, &args.name)?;
-    reboot_instance(&aws, &instance.instance_id)?;
-
-    println!(
-        "rebooted name={} instance-id={}",
-        args.name, instance.instance_id
-    );
+    let vpc_id = ensure_vpc(&aws, &config)?;
+    let subnet_ids = ensure_subnet(&aws, &config, &vpc_id)?;
+    let primary_subnet_id = subnet_ids
+        .first()
+        .cloned()
+        .ok_or_else(|| anyhow!("no availability zones configured for cluster {}", config.cluster_name))?;
+    let igw_id = ensure_internet_gateway(&aws, &config, &vpc_id)?;
+    ensure_route_table(&aws, &config, &vpc_id, &subnet_ids, &igw_id)?;
+    let sg_id = ensure_security_group(&aws, &config, &vpc_id)?;
+    let key_name = ensure_key_pair(&aws, &config)?;
+    let ami_id = resolve_ami_id(&aws, &config)?;
+    let instance_type = resolve_instance_type(&config, args.instance_type.clone());
+    let user_data = resolve_user_data(args.user_data.as_deref(), args.user_data_inline.as_deref())?
+        .or_else(|| config.user_data.clone());
+    let user_data = merge_ssh_authorized_keys(user_data, &args.ssh_key)?;
+    let placement_group = args
+        .placement_group_strategy
+        .map(|strategy| ensure_placement_group(&aws, &config, strategy, args.placement_group_partitions))
+        .transpose()?;
+    let expire_at = match args.lifetime.as_deref() {
+        Some(lifetime) => Some(unix_timestamp_now()? + parse_lifetime_secs(lifetime)?),
+        None => None,
+    };
+
+    if aws.plan_mutation(
+        PlanChange::Create,
+        "ec2 instance batch",
+        format!(
+            "name-prefix={}, count={}, ami={}, instance-type={}",
+            args.name_prefix, args.count, ami_id, instance_type
+        ),
+    ) {
+        aws.print_plan();
+        return Ok(());
+    }
+
+    let names: Vec<String> = (0..args.count).map(|i| format!("{}-{}", args.name_prefix, i)).collect();
+    let concurrency = args.concurrency.unwrap_or_else(default_concurrency);
+
+    let results = run_with_concurrency(names, concurrency, |name| {
+        let outcome = (|| -> Result<(String, Option<String>)> {
+            ensure_no_duplicate_instance(&aws, &config.cluster_name, &name)?;
+            let instance_id = launch_instance(
+                &aws,
+                &config,
+                config_root,
+                &name,
+                &ami_id,
+                &instance_type,
+                &primary_subnet_id,
+                &sg_id,
+                &key_name,
+                user_data.as_deref(),
+                None,
+                expire_at,
+                placement_group.as_deref(),
+            )?;
+            wait_for_instance_running(&aws, &instance_id)?;
+            wait_for_instance_status_checks_ok(&aws, &instance_id, "running", INSTANCE_STATUS_CHECKS_TIMEOUT)?;
+            let public_ip = fetch_instance_public_ip(&aws, &instance_id)?;
+            Ok((instance_id, public_ip))
+        })();
+        (name, outcome)
+    });
+
+    let mut failed = 0;
+    for (name, outcome) in &results {
+        match outcome {
+            Ok((instance_id, public_ip)) => {
+                println!(
+                    "name={} instance-id={} public-ip={}",
+                    name,
+                    instance_id,
+                    public_ip.as_deref().unwrap_or("N/A")
+                );
+            }
+            Err(err) => {
+                failed += 1;
+                println!("name={} error={:#}", name, err);
+            }
+        }
+    }
+
+    print_aws_status_and_refresh_ssh_config(&aws, &config)?;
+
+    if failed > 0 {
+        bail!("{} of {} nodes failed to provision", failed, results.len());
+    }
+    Ok(())
+}
+
+fn run_aws_reboot(
+    args: RebootArgs,
+    config_root: &Path,
+    backend: AwsBackend,
+    profile: Option<String>,
+) -> Result<()> {
+    ensure_no_profile_env(profile.as_deref())?;
+    check_aws_cli()?;
+    let config = load_aws_config(config_root, &args.cluster, args.config.as_deref(), profile.as_deref())?;
+    let region = config.region.clone();
+    let aws = AwsCli::new_with_backend(region, backend, profile.clone());
+    print_banner(&aws)?;
+
+    let instance = find_instance_by_name(&aws, &args.name)?;
+    reboot_instance(
+        &aws,
+        config_root,
+        &config.cluster_name,
+        &args.name,
+        &instance.instance_id,
+    )?;
+
+    println!(
+        "rebooted name={} instance-id={}",
+        args.name, instance.instance_id
+    );
     Ok(())
 }
 
-fn run_aws_health(args: Ec2HealthArgs, config_root: &Path) -> Result<()> {
+fn run_aws_health(
+    args: Ec2HealthArgs,
+    config_root: &Path,
+    backend: AwsBackend,
+    profile: Option<String>,
+) -> Result<()> {
     ensure_vmcli_ssh_keypair(config_root)?;
-    ensure_no_profile_env()?;
+    ensure_no_profile_env(profile.as_deref())?;
     check_aws_cli()?;
-    let config = load_aws_config(config_root, &args.cluster, args.config.as_deref())?;
+    let config = load_aws_config(config_root, &args.cluster, args.config.as_deref(), profile.as_deref())?;
     let region = config.region.clone();
-    let aws = AwsCli::new(region);
+    let aws = AwsCli::new_with_backend(region, backend, profile.clone());
     print_banner(&aws)?;
 
-    let instance = find_instance_by_cluster_and_name(&aws, &config.cluster_name, &args.name)?;
-    let ec2_checks = describe_ec2_status_checks(&aws, &instance.instance_id, &instance.state.name)?;
+    let tcp_timeout = Duration::from_secs(args.tcp_timeout_secs);
+
+    match args.name.as_ref() {
+        Some(name) => {
+            let instance = find_instance_by_cluster_and_name(&aws, &config.cluster_name, name)?;
+            let (ec2_checks, eic_probe, tcp_probe, summary) =
+                check_instance_health(&aws, &config, &instance, &args.os_user, tcp_timeout)?;
+            if args.json {
+                let report = Ec2HealthReport {
+                    cluster: &config.cluster_name,
+                    name,
+                    instance: &instance,
+                    ec2_status_checks: &ec2_checks,
+                    eic_probe: &eic_probe,
+                    tcp_probe: &tcp_probe,
+                    summary: &summary,
+                };
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                print_health_report(
+                    &config.cluster_name,
+                    name,
+                    &instance,
+                    &ec2_checks,
+                    &eic_probe,
+                    &tcp_probe,
+                    &summary,
+                );
+            }
+        }
+        None => {
+            let vpc_id = find_vpc(&aws, &config.cluster_name)?
+                .ok_or_else(|| anyhow!("no vpc found for cluster '{}'", config.cluster_name))?;
+            let mut instances = describe_instances_by_vpc(&aws, &vpc_id)?;
+            instances.sort_by(|a, b| tag_value(&a.tags, "Name").cmp(&tag_value(&b.tags, "Name")));
+
+            let concurrency = args.concurrency.unwrap_or_else(default_concurrency);
+            let reports = run_with_concurrency(instances, concurrency, |instance| {
+                let result = check_instance_health(&aws, &config, &instance, &args.os_user, tcp_timeout);
+                (instance, result)
+            });
+
+            let mut json_reports = Vec::new();
+            for (instance, result) in reports {
+                let name =
+                    tag_value(&instance.tags, "Name").unwrap_or_else(|| instance.instance_id.clone());
+                match result {
+                    Ok((ec2_checks, eic_probe, tcp_probe, summary)) => {
+                        if args.json {
+                            let report = Ec2HealthReport {
+                                cluster: &config.cluster_name,
+                                name: &name,
+                                instance: &instance,
+                                ec2_status_checks: &ec2_checks,
+                                eic_probe: &eic_probe,
+                                tcp_probe: &tcp_probe,
+                                summary: &summary,
+                            };
+                            json_reports.push(serde_json::to_value(&report)?);
+                        } else {
+                            print_health_report(
+                                &config.cluster_name,
+                                &name,
+                                &instance,
+                                &ec2_checks,
+                                &eic_probe,
+                                &tcp_probe,
+                                &summary,
+                            );
+                        }
+                    }
+                    Err(err) => {
+                        if args.json {
+                            json_reports.push(serde_json::json!({
+                                "cluster": config.cluster_name,
+                                "name": name,
+                                "instance_id": instance.instance_id,
+                                "error": format!("{:#}", err),
+                            }));
+                        } else {
+                            println!(
+                                "name={} instance-id={} health-check-error={}",
+                                name, instance.instance_id, err
+                            );
+                        }
+                    }
+                }
+            }
+            if args.json {
+                println!("{}", serde_json::to_string_pretty(&json_reports)?);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The flat, serializable combination of every `ec2 health` signal, printed
+/// by `--json` in place of the key=value lines from `print_health_report`.
+/// Unlike `one_line_value` (used by the text path to keep a probe reason on
+/// one line for grepping), reason strings here are passed through verbatim
+/// since JSON already escapes embedded newlines.
+#[derive(Serialize)]
+struct Ec2HealthReport<'a> {
+    cluster: &'a str,
+    name: &'a str,
+    instance: &'a Instance,
+    ec2_status_checks: &'a Ec2StatusChecks,
+    eic_probe: &'a EicProbeResult,
+    tcp_probe: &'a TcpSshProbeResult,
+    summary: &'a HealthSummary,
+}
+
+/// Runs the EC2 status checks, EIC reachability probe, direct TCP/SSH probe,
+/// and summary for a single instance; shared by the single-name and
+/// whole-cluster `ec2 health` paths.
+fn check_instance_health(
+    aws: &AwsCli,
+    config: &AwsEffectiveConfig,
+    instance: &Instance,
+    os_user: &str,
+    tcp_timeout: Duration,
+) -> Result<(Ec2StatusChecks, EicProbeResult, TcpSshProbeResult, HealthSummary)> {
+    let ec2_checks = describe_ec2_status_checks(aws, &instance.instance_id, &instance.state.name)?;
 
-    let sg_ids = instance_security_group_ids(&instance);
-    let security_groups = describe_security_groups_by_ids(&aws, &sg_ids)?;
+    let sg_ids = instance_security_group_ids(instance);
+    let security_groups = describe_security_groups_by_ids(aws, &sg_ids)?;
     let sg_port22 = classify_sg_port_22(&security_groups);
 
-    let eic_probe = run_eic_probe(&aws, &config, &instance, sg_port22, &args.os_user)?;
-    let summary = summarize_health(&instance.state.name, ec2_checks.checks_pass, &eic_probe);
+    let eic_probe = run_eic_probe(aws, config, instance, sg_port22, os_user)?;
+    let tcp_probe = run_tcp_ssh_probe(instance.public_ip.as_deref(), tcp_timeout);
+    let summary = summarize_health(&instance.state.name, ec2_checks.checks_pass, &eic_probe, &tcp_probe);
+    Ok((ec2_checks, eic_probe, tcp_probe, summary))
+}
+
+fn run_aws_exec(
+    args: Ec2ExecArgs,
+    config_root: &Path,
+    backend: AwsBackend,
+    profile: Option<String>,
+) -> Result<()> {
+    ensure_vmcli_ssh_keypair(config_root)?;
+    ensure_no_profile_env(profile.as_deref())?;
+    check_aws_cli()?;
+    let config = load_aws_config(config_root, &args.cluster, args.config.as_deref(), profile.as_deref())?;
+    let region = config.region.clone();
+    let aws = AwsCli::new_with_backend(region, backend, profile.clone());
+
+    let identity_file = derive_private_key_path(&config.ssh_public_key_path);
+
+    match args.name.as_ref() {
+        Some(name) => {
+            let instance = find_instance_by_cluster_and_name(&aws, &config.cluster_name, name)?;
+            let public_ip = instance
+                .public_ip
+                .ok_or_else(|| anyhow!("instance '{}' has no public ip", name))?;
+            let exit_code = ssh_exec_command(
+                &public_ip,
+                &args.os_user,
+                &identity_file,
+                args.boot_timeout_secs,
+                &args.command,
+            )?;
+            std::process::exit(exit_code);
+        }
+        None => {
+            let vpc_id = find_vpc(&aws, &config.cluster_name)?
+                .ok_or_else(|| anyhow!("no vpc found for cluster '{}'", config.cluster_name))?;
+            let mut instances = describe_instances_by_vpc(&aws, &vpc_id)?;
+            instances.sort_by(|a, b| tag_value(&a.tags, "Name").cmp(&tag_value(&b.tags, "Name")));
+            let targets = instances
+                .into_iter()
+                .map(|instance| {
+                    let name =
+                        tag_value(&instance.tags, "Name").unwrap_or_else(|| instance.instance_id.clone());
+                    (name, instance.public_ip)
+                })
+                .collect();
+            let concurrency = args.concurrency.unwrap_or_else(default_concurrency);
+            fan_out_ssh_exec(
+                targets,
+                &args.os_user,
+                &identity_file,
+                args.boot_timeout_secs,
+                &args.command,
+                concurrency,
+            )
+        }
+    }
+}
+
+/// Pushes a ~60s ephemeral EC2 Instance Connect key for `args.ssh_user`, then
+/// execs the system `ssh` for a genuinely interactive session (raw terminal
+/// passthrough, resizing, signals) rather than hand-rolling a pty over the
+/// `ssh2` crate. Reuses `eic_send_key_skip_reason` so an instance that isn't
+/// ready fails fast with the same diagnostics as `ec2 health`.
+fn run_aws_ssh(
+    args: Ec2SshArgs,
+    config_root: &Path,
+    backend: AwsBackend,
+    profile: Option<String>,
+) -> Result<()> {
+    ensure_vmcli_ssh_keypair(config_root)?;
+    ensure_no_profile_env(profile.as_deref())?;
+    check_aws_cli()?;
+    let config = load_aws_config(config_root, &args.cluster, args.config.as_deref(), profile.as_deref())?;
+    let region = config.region.clone();
+    let aws = AwsCli::new_with_backend(region, backend, profile.clone());
+
+    let instance = find_instance_by_cluster_and_name(&aws, &config.cluster_name, &args.name)?;
+    let instance_running = instance.state.name == "running";
+    let public_ip_present = instance
+        .public_ip
+        .as_deref()
+        .map(|ip| !ip.trim().is_empty())
+        .unwrap_or(false);
+    let availability_zone = instance_availability_zone(&instance).map(|az| az.to_string());
+    let az_present = availability_zone.is_some();
+    let public_key_path = expand_home_path(&config.ssh_public_key_path)?;
+    let public_key_exists = public_key_path.exists();
+
+    if let Some(reason) = eic_send_key_skip_reason(
+        instance_running,
+        public_ip_present,
+        az_present,
+        public_key_exists,
+    ) {
+        bail!("cannot ssh to '{}': {}", args.name, reason);
+    }
 
-    print_health_report(
+    send_ephemeral_ssh_public_key(
+        &aws,
+        config_root,
         &config.cluster_name,
         &args.name,
-        &instance,
-        &ec2_checks,
-        &eic_probe,
-        &summary,
+        &instance.instance_id,
+        &args.ssh_user,
+        availability_zone.as_deref().unwrap(),
+        &public_key_path,
+    )?;
+
+    let identity_file = args
+        .identity_file
+        .unwrap_or_else(|| derive_private_key_path(&config.ssh_public_key_path));
+    let public_ip = instance.public_ip.unwrap();
+
+    let use_agent = args.use_agent
+        && if env::var_os("SSH_AUTH_SOCK").is_some() {
+            true
+        } else {
+            println!("--use-agent requested but SSH_AUTH_SOCK is not set; falling back to identity-file mode");
+            false
+        };
+
+    if use_agent {
+        load_key_into_agent(&identity_file, EIC_KEY_LIFETIME_SECS)?;
+    }
+
+    println!(
+        "connecting name={} instance-id={} public-ip={}",
+        args.name, instance.instance_id, public_ip
     );
 
+    let mut cmd = Command::new("ssh");
+    cmd.arg("-p").arg(args.ssh_port.to_string());
+    if use_agent {
+        cmd.arg("-o").arg("IdentityAgent=SSH_AUTH_SOCK");
+    } else {
+        cmd.arg("-i").arg(&identity_file);
+        cmd.arg("-o").arg("IdentitiesOnly=yes");
+    }
+    cmd.arg("-o")
+        .arg("StrictHostKeyChecking=no")
+        .arg("-o")
+        .arg("UserKnownHostsFile=/dev/null")
+        .arg(format!("{}@{}", args.ssh_user, public_ip));
+    let status = cmd.status().context("failed to execute ssh")?;
+
+    if use_agent {
+        if let Err(err) = remove_key_from_agent(&identity_file) {
+            eprintln!("warning: failed to remove key from ssh-agent: {:#}", err);
+        }
+    }
+
+    std::process::exit(status.code().unwrap_or(1));
+}
+
+/// Loads `identity_file`'s private key into the running ssh-agent
+/// (`SSH_AUTH_SOCK`) for `lifetime_secs`, matching the EIC key's validity
+/// window so stale keys don't accumulate in the agent. `ssh-add` detects the
+/// key type itself, so this works for both RSA and Ed25519 keys.
+fn load_key_into_agent(identity_file: &str, lifetime_secs: u64) -> Result<()> {
+    let output = Command::new("ssh-add")
+        .arg("-t")
+        .arg(lifetime_secs.to_string())
+        .arg(identity_file)
+        .output()
+        .context("failed to execute ssh-add")?;
+    if !output.status.success() {
+        bail!(
+            "ssh-add failed to load {}: {}",
+            identity_file,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+/// Removes `identity_file`'s key from the running ssh-agent once the session
+/// ends, rather than waiting out the full `ssh-add -t` lifetime.
+fn remove_key_from_agent(identity_file: &str) -> Result<()> {
+    let output = Command::new("ssh-add")
+        .arg("-d")
+        .arg(identity_file)
+        .output()
+        .context("failed to execute ssh-add -d")?;
+    if !output.status.success() {
+        bail!(
+            "ssh-add failed to remove {}: {}",
+            identity_file,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
     Ok(())
 }
 
-fn run_aws_destroy(args: DestroyArgs, config_root: &Path) -> Result<()> {
-    ensure_no_profile_env()?;
+fn run_aws_destroy(
+    args: DestroyArgs,
+    config_root: &Path,
+    backend: AwsBackend,
+    profile: Option<String>,
+) -> Result<()> {
+    ensure_no_profile_env(profile.as_deref())?;
     check_aws_cli()?;
-    let config = load_aws_config(config_root, &args.cluster, args.config.as_deref())?;
+    let config = load_aws_config(config_root, &args.cluster, args.config.as_deref(), profile.as_deref())?;
     let region = config.region.clone();
-    let aws = AwsCli::new(region);
+    let aws = AwsCli::new_with_backend(region, backend, profile.clone());
     print_banner(&aws)?;
 
     let instance = find_instance_by_name(&aws, &args.name)?;
@@ -1219,7 +3301,13 @@ fn run_aws_destroy(args: DestroyArgs, config_root: &Path) -> Result<()> {
         }
     }
 
-    terminate_instance(&aws, &instance.instance_id)?;
+    terminate_instance(
+        &aws,
+        config_root,
+        &config.cluster_name,
+        &args.name,
+        &instance.instance_id,
+    )?;
     wait_for_instance_terminated(&aws, &instance.instance_id)?;
     println!(
         "terminated name={} instance-id={}",
@@ -1229,17 +3317,81 @@ fn run_aws_destroy(args: DestroyArgs, config_root: &Path) -> Result<()> {
     Ok(())
 }
 
-fn run_aws_status(args: StatusArgs, config_root: &Path) -> Result<()> {
-    ensure_no_profile_env()?;
+fn run_aws_status(
+    args: StatusArgs,
+    config_root: &Path,
+    backend: AwsBackend,
+    profile: Option<String>,
+) -> Result<()> {
+    ensure_no_profile_env(profile.as_deref())?;
     check_aws_cli()?;
-    let config = load_aws_config(config_root, &args.cluster, args.config.as_deref())?;
+    let config = load_aws_config(config_root, &args.cluster, args.config.as_deref(), profile.as_deref())?;
     let region = config.region.clone();
-    let aws = AwsCli::new(region);
+    let aws = AwsCli::new_with_backend(region, backend, profile.clone());
     print_banner(&aws)?;
 
     print_aws_status_and_refresh_ssh_config(&aws, &config)
 }
 
+fn run_aws_config(args: StatusArgs, config_root: &Path, profile: Option<String>) -> Result<()> {
+    let cluster_path = match args.config.as_deref() {
+        Some(path) => PathBuf::from(path),
+        None => ec2_cluster_config_path(config_root, &args.cluster)?,
+    };
+    let (_, figment) =
+        load_layered_cluster_config(config_root, EC2_PROVIDER, &args.cluster, &cluster_path)?;
+    let config = load_aws_config(
+        config_root,
+        &args.cluster,
+        args.config.as_deref(),
+        profile.as_deref(),
+    )?;
+
+    println!("provider=ec2 cluster={}", args.cluster);
+    print_config_field(
+        "region",
+        &config.region,
+        config_field_source(&figment, &cluster_path, EC2_PROVIDER, "region"),
+    );
+    print_config_field(
+        "ssh_public_key_path",
+        &config.ssh_public_key_path,
+        config_field_source(&figment, &cluster_path, EC2_PROVIDER, "ssh_public_key_path"),
+    );
+    print_config_field(
+        "default_instance_type",
+        &config.default_instance_type,
+        config_field_source(
+            &figment,
+            &cluster_path,
+            EC2_PROVIDER,
+            "default_instance_type",
+        ),
+    );
+    print_optional_config_field(
+        "ami_id",
+        config.ami_id.as_deref(),
+        config_field_source(&figment, &cluster_path, EC2_PROVIDER, "ami_id"),
+    );
+    print_optional_config_field(
+        "user_data",
+        config.user_data.as_deref(),
+        config_field_source(&figment, &cluster_path, EC2_PROVIDER, "user_data"),
+    );
+    print_config_field(
+        "vpc_cidr",
+        &config.vpc_cidr,
+        config_field_source(&figment, &cluster_path, EC2_PROVIDER, "vpc_cidr"),
+    );
+    for spec in &config.subnets {
+        println!(
+            "subnet availability_zone={} cidr={}",
+            spec.availability_zone, spec.cidr
+        );
+    }
+    Ok(())
+}
+
 fn print_aws_status_and_refresh_ssh_config(
     aws: &AwsCli,
     config: &AwsEffectiveConfig,
@@ -1257,9 +3409,13 @@ fn print_aws_status_and_refresh_ssh_config(
         .into_iter()
         .map(|instance| InstanceEntry {
             name: tag_value(&instance.tags, "Name"),
-            instance_id: instance.instance_id,
-            state: instance.state.name,
-            public_ip: instance.public_ip,
+            instance_id: instance.instance_id.clone(),
+            state: instance.state.name.clone(),
+            public_ip: instance.public_ip.clone(),
+            region: Some(config.region.clone()),
+            spot: instance.is_spot().then_some(SpotStatus {
+                interrupted: instance.was_spot_interrupted(),
+            }),
         })
         .collect::<Vec<_>>();
 
@@ -1269,11 +3425,13 @@ fn print_aws_status_and_refresh_ssh_config(
     for entry in &entries {
         let public_ip = entry.public_ip.as_deref().unwrap_or("N/A");
         println!(
-            "name={} instance-id={} state={} public-ip={}",
+            "name={} instance-id={} state={} public-ip={} lifecycle={} interrupted={}",
             entry.display_name(),
             entry.instance_id,
             entry.state,
-            public_ip
+            public_ip,
+            entry.lifecycle_label(),
+            entry.spot.is_some_and(|spot| spot.interrupted)
         );
     }
 
@@ -1289,12 +3447,17 @@ fn print_aws_status_and_refresh_ssh_config(
     Ok(())
 }
 
-fn run_aws_prune(args: PruneArgs, config_root: &Path) -> Result<()> {
-    ensure_no_profile_env()?;
+fn run_aws_prune(
+    args: PruneArgs,
+    config_root: &Path,
+    backend: AwsBackend,
+    profile: Option<String>,
+) -> Result<()> {
+    ensure_no_profile_env(profile.as_deref())?;
     check_aws_cli()?;
-    let config = load_aws_config(config_root, &args.cluster, args.config.as_deref())?;
+    let config = load_aws_config(config_root, &args.cluster, args.config.as_deref(), profile.as_deref())?;
     let region = config.region.clone();
-    let aws = AwsCli::new(region);
+    let aws = AwsCli::new_with_backend(region, backend, profile.clone());
     print_banner(&aws)?;
 
     let vpc_id = match find_vpc(&aws, &config.cluster_name)? {
@@ -1342,9 +3505,8 @@ fn run_aws_prune(args: PruneArgs, config_root: &Path) -> Result<()> {
         delete_route_table(&aws, &route_table.route_table_id)?;
     }
 
-    let subnet_id = find_subnet(&aws, &config.cluster_name)?;
-    if let Some(subnet_id) = subnet_id.as_ref() {
-        delete_subnet(&aws, subnet_id)?;
+    for subnet_id in find_subnets_by_cluster(&aws, &config.cluster_name)? {
+        delete_subnet(&aws, &subnet_id)?;
     }
 
     let igw = find_internet_gateway(&aws, &config.cluster_name)?;
@@ -1380,91 +3542,410 @@ fn run_aws_prune(args: PruneArgs, config_root: &Path) -> Result<()> {
     Ok(())
 }
 
-fn run_aws_init(args: InitArgs, config_root: &Path) -> Result<()> {
-    ensure_vmcli_ssh_keypair(config_root)?;
-    let config_dir = ec2_cluster_dir(config_root, &args.cluster)?;
-    fs::create_dir_all(&config_dir)
-        .with_context(|| format!("create config dir {}", config_dir.display()))?;
+fn run_aws_reap(
+    args: ReapArgs,
+    config_root: &Path,
+    backend: AwsBackend,
+    profile: Option<String>,
+) -> Result<()> {
+    ensure_no_profile_env(profile.as_deref())?;
+    check_aws_cli()?;
+    let config = load_aws_config(config_root, &args.cluster, args.config.as_deref(), profile.as_deref())?;
+    let region = config.region.clone();
+    let aws = AwsCli::new_with_backend(region, backend, profile.clone());
+    print_banner(&aws)?;
 
-    let config_path = config_dir.join(CONFIG_FILE_NAME);
-    let ssh_config_path = config_dir.join(SSH_CONFIG_FILE);
+    let vpc_id = match find_vpc(&aws, &config.cluster_name)? {
+        Some(vpc_id) => vpc_id,
+        None => {
+            println!("no vpc found for cluster '{}'; nothing to reap", config.cluster_name);
+            return Ok(());
+        }
+    };
 
-    if !config_path.exists() {
-        let defaults = load_global_config(config_root)?;
-        let public_key_path = defaults
-            .ec2
-            .as_ref()
-            .and_then(|aws| aws.ssh_public_key_path.clone())
-            .unwrap_or_else(|| default_ssh_public_key_path(config_root));
-        let region = defaults
-            .ec2
-            .as_ref()
-            .and_then(|aws| aws.region.clone())
-            .unwrap_or_else(|| "ap-northeast-1".to_string());
-        let default_instance_type = defaults
-            .ec2
-            .as_ref()
-            .and_then(|aws| aws.default_instance_type.clone())
-            .unwrap_or_else(|| DEFAULT_INSTANCE_TYPE.to_string());
-        let contents = default_ec2_config_contents(
-            &args.cluster,
-            &region,
-            &public_key_path,
-            &default_instance_type,
+    let now = unix_timestamp_now()?;
+    let instances = describe_instances_by_vpc(&aws, &vpc_id)?;
+    let mut reaped = 0;
+    for instance in instances {
+        let Some(expire_at) = tag_value(&instance.tags, "VmcliExpireAt").and_then(|value| value.parse::<u64>().ok())
+        else {
+            continue;
+        };
+        if expire_at > now {
+            continue;
+        }
+        let name = tag_value(&instance.tags, "Name").unwrap_or_else(|| instance.instance_id.clone());
+        terminate_instance(
+            &aws,
+            config_root,
+            &config.cluster_name,
+            &name,
+            &instance.instance_id,
+        )?;
+        wait_for_instance_terminated(&aws, &instance.instance_id)?;
+        println!(
+            "reaped name={} instance-id={} expired-at={}",
+            name, instance.instance_id, expire_at
         );
-        fs::write(&config_path, contents)
-            .with_context(|| format!("write {}", config_path.display()))?;
-        println!("created {}", config_path.display());
-    } else {
-        println!("exists {}", config_path.display());
+        reaped += 1;
     }
 
-    if !ssh_config_path.exists() {
-        fs::write(&ssh_config_path, "")
-            .with_context(|| format!("write {}", ssh_config_path.display()))?;
-        println!("created {}", ssh_config_path.display());
-    } else {
-        println!("exists {}", ssh_config_path.display());
+    if reaped == 0 {
+        println!("no expired instances found in cluster '{}'", config.cluster_name);
     }
     Ok(())
 }
 
-fn run_lightsail_init(args: InitArgs, config_root: &Path) -> Result<()> {
-    ensure_vmcli_ssh_keypair(config_root)?;
-    let config_dir = lightsail_cluster_dir(config_root, &args.cluster)?;
-    fs::create_dir_all(&config_dir)
-        .with_context(|| format!("create config dir {}", config_dir.display()))?;
+fn run_aws_firewall(
+    args: FirewallArgs,
+    config_root: &Path,
+    backend: AwsBackend,
+    profile: Option<String>,
+) -> Result<()> {
+    ensure_no_profile_env(profile.as_deref())?;
+    check_aws_cli()?;
+    let config = load_aws_config(config_root, &args.cluster, args.config.as_deref(), profile.as_deref())?;
+    let region = config.region.clone();
+    let aws = AwsCli::new_with_backend(region, backend, profile.clone());
+    print_banner(&aws)?;
 
-    let config_path = config_dir.join(CONFIG_FILE_NAME);
-    let ssh_config_path = config_dir.join(SSH_CONFIG_FILE);
+    let sg_id = find_security_group(&aws, &config.cluster_name)?.ok_or_else(|| {
+        anyhow!(
+            "no security group found for cluster '{}'; run `ec2 up` first",
+            config.cluster_name
+        )
+    })?;
+    let security_groups = describe_security_groups_by_ids(&aws, &[sg_id.clone()])?;
+    let live = sg_rule_keys(&security_groups);
+    let desired = config
+        .firewall
+        .iter()
+        .map(firewall_rule_key)
+        .collect::<Vec<_>>();
 
-    if !config_path.exists() {
-        let defaults = load_global_config(config_root)?;
-        let public_key_path = defaults
-            .lightsail
-            .as_ref()
-            .and_then(|value| value.ssh_public_key_path.clone())
-            .unwrap_or_else(|| default_ssh_public_key_path(config_root));
-        let region = defaults
-            .lightsail
-            .as_ref()
-            .and_then(|value| value.region.clone())
-            .unwrap_or_else(|| "ap-northeast-1".to_string());
-        let availability_zone = defaults
-            .lightsail
-            .as_ref()
-            .and_then(|value| value.availability_zone.clone())
-            .unwrap_or_else(|| format!("{}a", region));
-        let default_bundle_id = defaults
-            .lightsail
-            .as_ref()
-            .and_then(|value| value.default_bundle_id.clone())
+    let to_add = config
+        .firewall
+        .iter()
+        .filter(|rule| !live.contains(&firewall_rule_key(rule)))
+        .collect::<Vec<_>>();
+    let to_remove = live
+        .iter()
+        .filter(|key| !desired.contains(key))
+        .collect::<Vec<_>>();
+
+    match args.action {
+        FirewallAction::List => {
+            for rule in &config.firewall {
+                let state = if live.contains(&firewall_rule_key(rule)) {
+                    "open"
+                } else {
+                    "missing"
+                };
+                println!(
+                    "protocol={} port={} cidr={} state={}",
+                    rule.protocol,
+                    firewall_port_range(rule),
+                    rule.cidr,
+                    state
+                );
+            }
+            for (protocol, from_port, to_port, source) in &to_remove {
+                let rule = firewall_rule_from_key(protocol, *from_port, *to_port, source);
+                println!(
+                    "protocol={} port={} cidr={} state=unconfigured",
+                    rule.protocol,
+                    firewall_port_range(&rule),
+                    rule.cidr
+                );
+            }
+        }
+        FirewallAction::Allow => {
+            for rule in &to_add {
+                authorize_sg_ingress(&aws, &sg_id, rule)?;
+                println!(
+                    "allowed protocol={} port={} cidr={}",
+                    rule.protocol,
+                    firewall_port_range(rule),
+                    rule.cidr
+                );
+            }
+            if to_add.is_empty() {
+                println!("security group already matches configured firewall rules");
+            }
+        }
+        FirewallAction::Revoke => {
+            for (protocol, from_port, to_port, source) in &to_remove {
+                let rule = firewall_rule_from_key(protocol, *from_port, *to_port, source);
+                revoke_sg_ingress(&aws, &sg_id, &rule)?;
+                println!(
+                    "revoked protocol={} port={} cidr={}",
+                    rule.protocol,
+                    firewall_port_range(&rule),
+                    rule.cidr
+                );
+            }
+            if to_remove.is_empty() {
+                println!("no unconfigured rules to revoke");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// One managed AWS resource's presence, as reported by `ec2 resources`.
+struct ResourceEntry {
+    resource: &'static str,
+    name: String,
+    id: Option<String>,
+    state: &'static str,
+}
+
+impl ResourceEntry {
+    fn found(resource: &'static str, name: String, id: String) -> Self {
+        ResourceEntry {
+            resource,
+            name,
+            id: Some(id),
+            state: "present",
+        }
+    }
+
+    fn missing(resource: &'static str, name: String) -> Self {
+        ResourceEntry {
+            resource,
+            name,
+            id: None,
+            state: "absent",
+        }
+    }
+}
+
+/// Enumerates the VPC/subnet/internet-gateway/route-table/security-group
+/// resources `ec2 up` provisions for `config`, on top of the same
+/// `find_vpc`/`find_subnet`/`find_internet_gateway`/`find_route_table`/
+/// `find_security_group` lookups `up`/`destroy`/`prune` already use, so the
+/// inventory always reflects what this module actually manages.
+fn collect_aws_resources(aws: &AwsCli, config: &AwsEffectiveConfig) -> Result<Vec<ResourceEntry>> {
+    let mut entries = Vec::new();
+
+    let vpc_name = resource_name(&config.cluster_name, "vpc");
+    entries.push(match find_vpc(aws, &config.cluster_name)? {
+        Some(id) => ResourceEntry::found("vpc", vpc_name, id),
+        None => ResourceEntry::missing("vpc", vpc_name),
+    });
+
+    for spec in &config.subnets {
+        let subnet_name = resource_name(&config.cluster_name, &format!("subnet-{}", spec.suffix()));
+        entries.push(match find_subnet(aws, &config.cluster_name, &subnet_name)? {
+            Some(id) => ResourceEntry::found("subnet", subnet_name, id),
+            None => ResourceEntry::missing("subnet", subnet_name),
+        });
+    }
+
+    let igw_name = resource_name(&config.cluster_name, "igw");
+    entries.push(match find_internet_gateway(aws, &config.cluster_name)? {
+        Some(igw) => ResourceEntry::found("internet-gateway", igw_name, igw.internet_gateway_id),
+        None => ResourceEntry::missing("internet-gateway", igw_name),
+    });
+
+    let rt_name = resource_name(&config.cluster_name, "rt");
+    entries.push(match find_route_table(aws, &config.cluster_name)? {
+        Some(rt) => ResourceEntry::found("route-table", rt_name, rt.route_table_id),
+        None => ResourceEntry::missing("route-table", rt_name),
+    });
+
+    let sg_name = resource_name(&config.cluster_name, "sg");
+    entries.push(match find_security_group(aws, &config.cluster_name)? {
+        Some(id) => ResourceEntry::found("security-group", sg_name, id),
+        None => ResourceEntry::missing("security-group", sg_name),
+    });
+
+    Ok(entries)
+}
+
+fn run_aws_resources(
+    args: ResourcesArgs,
+    config_root: &Path,
+    backend: AwsBackend,
+    profile: Option<String>,
+) -> Result<()> {
+    ensure_no_profile_env(profile.as_deref())?;
+    check_aws_cli()?;
+    let config = load_aws_config(config_root, &args.cluster, args.config.as_deref(), profile.as_deref())?;
+    let region = config.region.clone();
+    let aws = AwsCli::new_with_backend(region, backend, profile.clone());
+    print_banner(&aws)?;
+
+    let entries = collect_aws_resources(&aws, &config)?;
+
+    match args.output {
+        ResourcesOutput::Json => {
+            let records: Vec<_> = entries
+                .iter()
+                .map(|entry| {
+                    serde_json::json!({
+                        "resource": entry.resource,
+                        "name": entry.name,
+                        "id": entry.id,
+                        "state": entry.state,
+                    })
+                })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&records)?);
+        }
+        ResourcesOutput::Csv => {
+            println!("resource,name,id,state");
+            for entry in &entries {
+                println!(
+                    "{},{},{},{}",
+                    entry.resource,
+                    entry.name,
+                    entry.id.as_deref().unwrap_or(""),
+                    entry.state
+                );
+            }
+        }
+        ResourcesOutput::Table => {
+            for entry in &entries {
+                println!(
+                    "resource={} name={} id={} state={}",
+                    entry.resource,
+                    entry.name,
+                    entry.id.as_deref().unwrap_or("-"),
+                    entry.state
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn run_aws_init(args: InitArgs, config_root: &Path) -> Result<()> {
+    ensure_vmcli_ssh_keypair(config_root)?;
+    let config_dir = ec2_cluster_dir(config_root, &args.cluster)?;
+    fs::create_dir_all(&config_dir)
+        .with_context(|| format!("create config dir {}", config_dir.display()))?;
+
+    let config_path = config_dir.join(CONFIG_FILE_NAME);
+    let ssh_config_path = config_dir.join(SSH_CONFIG_FILE);
+
+    let already_existed = config_path.exists();
+    if already_existed && !args.wizard {
+        println!("exists {}", config_path.display());
+    } else {
+        if already_existed && !args.force {
+            bail!(
+                "config {} already exists; pass --force to overwrite with --wizard",
+                config_path.display()
+            );
+        }
+        let defaults = load_global_config(config_root)?;
+        let mut region = defaults
+            .ec2
+            .as_ref()
+            .and_then(|aws| aws.region.clone())
+            .unwrap_or_else(|| "ap-northeast-1".to_string());
+        let mut public_key_path = defaults
+            .ec2
+            .as_ref()
+            .and_then(|aws| aws.ssh_public_key_path.clone())
+            .unwrap_or_else(|| default_ssh_public_key_path(config_root));
+        let mut default_instance_type = defaults
+            .ec2
+            .as_ref()
+            .and_then(|aws| aws.default_instance_type.clone())
+            .unwrap_or_else(|| DEFAULT_INSTANCE_TYPE.to_string());
+
+        if args.wizard {
+            println!("configuring ec2 cluster '{}'", args.cluster);
+            region = prompt_wizard_field("region", &region, &ec2_region_choices())?;
+            public_key_path = prompt_wizard_public_key_path(&public_key_path)?;
+            default_instance_type =
+                prompt_wizard_field("default_instance_type", &default_instance_type, &[])?;
+        }
+
+        let contents = default_ec2_config_contents(
+            &args.cluster,
+            &region,
+            &public_key_path,
+            &default_instance_type,
+        );
+        write_config_secured(&config_path, &contents)?;
+        println!(
+            "{} {}",
+            if already_existed { "updated" } else { "created" },
+            config_path.display()
+        );
+    }
+
+    if !ssh_config_path.exists() {
+        fs::write(&ssh_config_path, "")
+            .with_context(|| format!("write {}", ssh_config_path.display()))?;
+        println!("created {}", ssh_config_path.display());
+    } else {
+        println!("exists {}", ssh_config_path.display());
+    }
+    Ok(())
+}
+
+fn run_lightsail_init(args: InitArgs, config_root: &Path) -> Result<()> {
+    ensure_vmcli_ssh_keypair(config_root)?;
+    let config_dir = lightsail_cluster_dir(config_root, &args.cluster)?;
+    fs::create_dir_all(&config_dir)
+        .with_context(|| format!("create config dir {}", config_dir.display()))?;
+
+    let config_path = config_dir.join(CONFIG_FILE_NAME);
+    let ssh_config_path = config_dir.join(SSH_CONFIG_FILE);
+
+    let already_existed = config_path.exists();
+    if already_existed && !args.wizard {
+        println!("exists {}", config_path.display());
+    } else {
+        if already_existed && !args.force {
+            bail!(
+                "config {} already exists; pass --force to overwrite with --wizard",
+                config_path.display()
+            );
+        }
+        let defaults = load_global_config(config_root)?;
+        let mut region = defaults
+            .lightsail
+            .as_ref()
+            .and_then(|value| value.region.clone())
+            .unwrap_or_else(|| "ap-northeast-1".to_string());
+        let mut public_key_path = defaults
+            .lightsail
+            .as_ref()
+            .and_then(|value| value.ssh_public_key_path.clone())
+            .unwrap_or_else(|| default_ssh_public_key_path(config_root));
+        let mut availability_zone = defaults
+            .lightsail
+            .as_ref()
+            .and_then(|value| value.availability_zone.clone())
+            .unwrap_or_else(|| format!("{}a", region));
+        let mut default_bundle_id = defaults
+            .lightsail
+            .as_ref()
+            .and_then(|value| value.default_bundle_id.clone())
             .unwrap_or_else(|| DEFAULT_LIGHTSAIL_BUNDLE_ID.to_string());
-        let blueprint_id = defaults
+        let mut blueprint_id = defaults
             .lightsail
             .as_ref()
             .and_then(|value| value.blueprint_id.clone())
             .unwrap_or_else(|| DEFAULT_LIGHTSAIL_BLUEPRINT_ID.to_string());
+
+        if args.wizard {
+            println!("configuring lightsail cluster '{}'", args.cluster);
+            region = prompt_wizard_field("region", &region, &lightsail_region_choices())?;
+            public_key_path = prompt_wizard_public_key_path(&public_key_path)?;
+            availability_zone =
+                prompt_wizard_field("availability_zone", &availability_zone, &[])?;
+            default_bundle_id =
+                prompt_wizard_field("default_bundle_id", &default_bundle_id, &[])?;
+            blueprint_id = prompt_wizard_field("blueprint_id", &blueprint_id, &[])?;
+        }
+
         let contents = default_lightsail_config_contents(
             &args.cluster,
             &region,
@@ -1473,11 +3954,12 @@ fn run_lightsail_init(args: InitArgs, config_root: &Path) -> Result<()> {
             &default_bundle_id,
             &blueprint_id,
         );
-        fs::write(&config_path, contents)
-            .with_context(|| format!("write {}", config_path.display()))?;
-        println!("created {}", config_path.display());
-    } else {
-        println!("exists {}", config_path.display());
+        write_config_secured(&config_path, &contents)?;
+        println!(
+            "{} {}",
+            if already_existed { "updated" } else { "created" },
+            config_path.display()
+        );
     }
 
     if !ssh_config_path.exists() {
@@ -1492,7 +3974,7 @@ fn run_lightsail_init(args: InitArgs, config_root: &Path) -> Result<()> {
 
 fn run_lightsail_up(args: LightsailUpArgs, config_root: &Path) -> Result<()> {
     ensure_vmcli_ssh_keypair(config_root)?;
-    ensure_no_profile_env()?;
+    ensure_no_profile_env(None)?;
     check_aws_cli()?;
     let config = load_lightsail_config(config_root, &args.cluster, args.config.as_deref())?;
     let aws = AwsCli::new(config.region.clone());
@@ -1528,13 +4010,24 @@ fn run_lightsail_up(args: LightsailUpArgs, config_root: &Path) -> Result<()> {
         create_args.push("--key-pair-name".to_string());
         create_args.push(key_pair_name.to_string());
     }
+    let user_data = resolve_user_data(args.user_data.as_deref(), args.user_data_inline.as_deref())?
+        .or_else(|| config.user_data.clone());
+    let user_data = merge_ssh_authorized_keys(user_data, &args.ssh_key)?;
+    if let Some(user_data) = user_data.as_deref() {
+        create_args.push("--user-data".to_string());
+        create_args.push(user_data.to_string());
+    }
     let _ = aws.run(&create_args)?;
 
-    ensure_lightsail_public_ports(&aws, &args.name)?;
+    ensure_lightsail_public_ports(&aws, &args.name, &config.firewall)?;
     lightsail_wait_for_instance_state(&aws, &config.cluster_name, &args.name, "running")?;
     let instance = lightsail_find_instance(&aws, &config.cluster_name, &args.name)?
         .ok_or_else(|| anyhow!("lightsail instance '{}' not found after create", args.name))?;
-    let public_ip = instance.public_ip.unwrap_or_else(|| "N/A".to_string());
+    let public_ip = if args.static_ip {
+        ensure_lightsail_static_ip(&aws, &config.cluster_name, &args.name)?
+    } else {
+        instance.public_ip.unwrap_or_else(|| "N/A".to_string())
+    };
     println!(
         "name={} instance-id={} public-ip={}",
         args.name, args.name, public_ip
@@ -1543,24 +4036,129 @@ fn run_lightsail_up(args: LightsailUpArgs, config_root: &Path) -> Result<()> {
     print_lightsail_status_and_refresh_ssh_config(&aws, &config)
 }
 
-fn ensure_lightsail_public_ports(aws: &AwsCli, instance_name: &str) -> Result<()> {
+/// Rebuilds the instance's full public-port set from `rules`. Lightsail's
+/// `put-instance-public-ports` API is inherently full-replace, so unlike the EC2
+/// security group path there is no incremental allow/revoke here: every call
+/// (including `lightsail firewall allow`/`revoke`) recomputes and resubmits the
+/// whole desired set from config.
+fn ensure_lightsail_public_ports(
+    aws: &AwsCli,
+    instance_name: &str,
+    rules: &[FirewallRule],
+) -> Result<()> {
     let mut args = aws_args(&[
         "lightsail",
         "put-instance-public-ports",
         "--instance-name",
         instance_name,
     ]);
-    for port in [22, 80, 443] {
+    for rule in rules {
         args.push("--port-infos".to_string());
-        args.push(format!("fromPort={},toPort={},protocol=tcp", port, port));
+        args.push(format!(
+            "fromPort={},toPort={},protocol={}",
+            rule.port,
+            rule.to_port.unwrap_or(rule.port),
+            rule.protocol
+        ));
+    }
+    let _ = aws.run(&args)?;
+    Ok(())
+}
+
+fn lightsail_static_ip_name(cluster: &str, instance_name: &str) -> String {
+    resource_name(&format!("{}-{}", cluster, instance_name), "static-ip")
+}
+
+fn lightsail_find_static_ip(aws: &AwsCli, name: &str) -> Result<Option<LightsailStaticIpInfo>> {
+    let args = aws_args(&[
+        "lightsail",
+        "get-static-ip",
+        "--static-ip-name",
+        name,
+        "--output",
+        "json",
+    ]);
+    let output = aws.run_output(&args)?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.contains("NotFoundException") {
+            return Ok(None);
+        }
+        bail!("failed to describe lightsail static ip: {}", stderr.trim());
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let payload: serde_json::Value =
+        serde_json::from_str(&stdout).context("parse lightsail get-static-ip")?;
+    let static_ip = payload
+        .get("staticIp")
+        .ok_or_else(|| anyhow!("lightsail get-static-ip response missing staticIp"))?;
+    let ip_address = static_ip
+        .get("ipAddress")
+        .and_then(|value| value.as_str())
+        .ok_or_else(|| anyhow!("lightsail static ip '{}' has no ipAddress", name))?
+        .to_string();
+    let is_attached = static_ip
+        .get("isAttached")
+        .and_then(|value| value.as_bool())
+        .unwrap_or(false);
+    Ok(Some(LightsailStaticIpInfo {
+        ip_address,
+        is_attached,
+    }))
+}
+
+/// Finds or allocates the static IP named after `cluster`+`instance_name`, attaches
+/// it to the instance, and returns its address; named deterministically so repeat
+/// calls (e.g. a re-run of `up`) reuse the same static IP instead of leaking one.
+fn ensure_lightsail_static_ip(aws: &AwsCli, cluster: &str, instance_name: &str) -> Result<String> {
+    let static_ip_name = lightsail_static_ip_name(cluster, instance_name);
+    if lightsail_find_static_ip(aws, &static_ip_name)?.is_none() {
+        let args = aws_args(&[
+            "lightsail",
+            "allocate-static-ip",
+            "--static-ip-name",
+            &static_ip_name,
+        ]);
+        let _ = aws.run(&args)?;
+    }
+
+    let attach_args = aws_args(&[
+        "lightsail",
+        "attach-static-ip",
+        "--static-ip-name",
+        &static_ip_name,
+        "--instance-name",
+        instance_name,
+    ]);
+    let _ = aws.run(&attach_args)?;
+
+    let info = lightsail_find_static_ip(aws, &static_ip_name)?
+        .ok_or_else(|| anyhow!("lightsail static ip '{}' not found after attach", static_ip_name))?;
+    Ok(info.ip_address)
+}
+
+fn release_lightsail_static_ip_if_exists(
+    aws: &AwsCli,
+    cluster: &str,
+    instance_name: &str,
+) -> Result<()> {
+    let static_ip_name = lightsail_static_ip_name(cluster, instance_name);
+    if lightsail_find_static_ip(aws, &static_ip_name)?.is_none() {
+        return Ok(());
     }
+    let args = aws_args(&[
+        "lightsail",
+        "release-static-ip",
+        "--static-ip-name",
+        &static_ip_name,
+    ]);
     let _ = aws.run(&args)?;
     Ok(())
 }
 
 fn run_lightsail_status(args: StatusArgs, config_root: &Path) -> Result<()> {
     ensure_vmcli_ssh_keypair(config_root)?;
-    ensure_no_profile_env()?;
+    ensure_no_profile_env(None)?;
     check_aws_cli()?;
     let config = load_lightsail_config(config_root, &args.cluster, args.config.as_deref())?;
     let aws = AwsCli::new(config.region.clone());
@@ -1568,9 +4166,110 @@ fn run_lightsail_status(args: StatusArgs, config_root: &Path) -> Result<()> {
     print_lightsail_status_and_refresh_ssh_config(&aws, &config)
 }
 
+fn run_lightsail_config(args: StatusArgs, config_root: &Path) -> Result<()> {
+    let cluster_path = match args.config.as_deref() {
+        Some(path) => PathBuf::from(path),
+        None => lightsail_cluster_config_path(config_root, &args.cluster)?,
+    };
+    let (_, figment) = load_layered_cluster_config(
+        config_root,
+        LIGHTSAIL_PROVIDER,
+        &args.cluster,
+        &cluster_path,
+    )?;
+    let config = load_lightsail_config(config_root, &args.cluster, args.config.as_deref())?;
+
+    println!("provider=lightsail cluster={}", args.cluster);
+    print_config_field(
+        "region",
+        &config.region,
+        config_field_source(&figment, &cluster_path, LIGHTSAIL_PROVIDER, "region"),
+    );
+    print_config_field(
+        "ssh_public_key_path",
+        &config.ssh_public_key_path,
+        config_field_source(
+            &figment,
+            &cluster_path,
+            LIGHTSAIL_PROVIDER,
+            "ssh_public_key_path",
+        ),
+    );
+    print_config_field(
+        "availability_zone",
+        &config.availability_zone,
+        config_field_source(
+            &figment,
+            &cluster_path,
+            LIGHTSAIL_PROVIDER,
+            "availability_zone",
+        ),
+    );
+    print_config_field(
+        "default_bundle_id",
+        &config.default_bundle_id,
+        config_field_source(
+            &figment,
+            &cluster_path,
+            LIGHTSAIL_PROVIDER,
+            "default_bundle_id",
+        ),
+    );
+    print_config_field(
+        "blueprint_id",
+        &config.blueprint_id,
+        config_field_source(&figment, &cluster_path, LIGHTSAIL_PROVIDER, "blueprint_id"),
+    );
+    print_optional_config_field(
+        "key_pair_name",
+        config.key_pair_name.as_deref(),
+        config_field_source(&figment, &cluster_path, LIGHTSAIL_PROVIDER, "key_pair_name"),
+    );
+    print_optional_config_field(
+        "user_data",
+        config.user_data.as_deref(),
+        config_field_source(&figment, &cluster_path, LIGHTSAIL_PROVIDER, "user_data"),
+    );
+    Ok(())
+}
+
+fn run_lightsail_firewall(args: FirewallArgs, config_root: &Path) -> Result<()> {
+    ensure_no_profile_env(None)?;
+    check_aws_cli()?;
+    let config = load_lightsail_config(config_root, &args.cluster, args.config.as_deref())?;
+    let aws = AwsCli::new(config.region.clone());
+    print_banner(&aws)?;
+
+    let entries = lightsail_list_cluster_instances(&aws, &config.cluster_name)?;
+    if entries.is_empty() {
+        println!("no instances found in cluster '{}'", config.cluster_name);
+        return Ok(());
+    }
+
+    match args.action {
+        FirewallAction::List => {
+            for rule in &config.firewall {
+                println!(
+                    "protocol={} port={} cidr={}",
+                    rule.protocol, rule.port, rule.cidr
+                );
+            }
+        }
+        // `put-instance-public-ports` always replaces the full set, so `allow` and
+        // `revoke` both just resubmit the configured rules for every instance.
+        FirewallAction::Allow | FirewallAction::Revoke => {
+            for entry in &entries {
+                ensure_lightsail_public_ports(&aws, &entry.name, &config.firewall)?;
+                println!("reconciled name={}", entry.name);
+            }
+        }
+    }
+    Ok(())
+}
+
 fn run_lightsail_health(args: HealthArgs, config_root: &Path) -> Result<()> {
     ensure_vmcli_ssh_keypair(config_root)?;
-    ensure_no_profile_env()?;
+    ensure_no_profile_env(None)?;
     check_aws_cli()?;
     let config = load_lightsail_config(config_root, &args.cluster, args.config.as_deref())?;
     let aws = AwsCli::new(config.region.clone());
@@ -1598,8 +4297,52 @@ fn run_lightsail_health(args: HealthArgs, config_root: &Path) -> Result<()> {
     Ok(())
 }
 
+fn run_lightsail_exec(args: ExecArgs, config_root: &Path) -> Result<()> {
+    ensure_vmcli_ssh_keypair(config_root)?;
+    ensure_no_profile_env(None)?;
+    check_aws_cli()?;
+    let config = load_lightsail_config(config_root, &args.cluster, args.config.as_deref())?;
+    let aws = AwsCli::new(config.region.clone());
+
+    let identity_file = derive_private_key_path(&config.ssh_public_key_path);
+
+    match args.name.as_ref() {
+        Some(name) => {
+            let instance = lightsail_find_instance(&aws, &config.cluster_name, name)?
+                .ok_or_else(|| anyhow!("lightsail instance '{}' not found in cluster", name))?;
+            let public_ip = instance
+                .public_ip
+                .ok_or_else(|| anyhow!("lightsail instance '{}' has no public ip", name))?;
+            let exit_code = ssh_exec_command(
+                &public_ip,
+                DEFAULT_INSTANCE_OS_USER,
+                &identity_file,
+                args.boot_timeout_secs,
+                &args.command,
+            )?;
+            std::process::exit(exit_code);
+        }
+        None => {
+            let entries = lightsail_list_cluster_instances(&aws, &config.cluster_name)?;
+            let targets = entries
+                .into_iter()
+                .map(|entry| (entry.name, entry.public_ip))
+                .collect();
+            let concurrency = args.concurrency.unwrap_or_else(default_concurrency);
+            fan_out_ssh_exec(
+                targets,
+                DEFAULT_INSTANCE_OS_USER,
+                &identity_file,
+                args.boot_timeout_secs,
+                &args.command,
+                concurrency,
+            )
+        }
+    }
+}
+
 fn run_lightsail_reboot(args: RebootArgs, config_root: &Path) -> Result<()> {
-    ensure_no_profile_env()?;
+    ensure_no_profile_env(None)?;
     check_aws_cli()?;
     let config = load_lightsail_config(config_root, &args.cluster, args.config.as_deref())?;
     let aws = AwsCli::new(config.region.clone());
@@ -1622,7 +4365,7 @@ fn run_lightsail_reboot(args: RebootArgs, config_root: &Path) -> Result<()> {
 }
 
 fn run_lightsail_destroy(args: DestroyArgs, config_root: &Path) -> Result<()> {
-    ensure_no_profile_env()?;
+    ensure_no_profile_env(None)?;
     check_aws_cli()?;
     let config = load_lightsail_config(config_root, &args.cluster, args.config.as_deref())?;
     let aws = AwsCli::new(config.region.clone());
@@ -1647,6 +4390,7 @@ fn run_lightsail_destroy(args: DestroyArgs, config_root: &Path) -> Result<()> {
         &instance.name,
     ]);
     let _ = aws.run(&destroy_args)?;
+    release_lightsail_static_ip_if_exists(&aws, &config.cluster_name, &instance.name)?;
     println!(
         "terminated name={} instance-id={}",
         instance.name, instance.name
@@ -1655,7 +4399,7 @@ fn run_lightsail_destroy(args: DestroyArgs, config_root: &Path) -> Result<()> {
 }
 
 fn run_lightsail_prune(args: PruneArgs, config_root: &Path) -> Result<()> {
-    ensure_no_profile_env()?;
+    ensure_no_profile_env(None)?;
     check_aws_cli()?;
     let config = load_lightsail_config(config_root, &args.cluster, args.config.as_deref())?;
     let aws = AwsCli::new(config.region.clone());
@@ -1693,6 +4437,7 @@ fn run_lightsail_prune(args: PruneArgs, config_root: &Path) -> Result<()> {
             &entry.name,
         ]);
         let _ = aws.run(&destroy_args)?;
+        release_lightsail_static_ip_if_exists(&aws, &config.cluster_name, &entry.name)?;
         println!("deleted name={}", entry.name);
     }
 
@@ -1714,23 +4459,31 @@ fn print_lightsail_status_and_refresh_ssh_config(
 ) -> Result<()> {
     let entries = lightsail_list_cluster_instances(aws, &config.cluster_name)?;
     println!("region={}", config.region);
+
+    let mut ssh_entries = Vec::with_capacity(entries.len());
     for entry in &entries {
-        let public_ip = entry.public_ip.as_deref().unwrap_or("N/A");
+        let static_ip_name = lightsail_static_ip_name(&config.cluster_name, &entry.name);
+        let static_ip =
+            lightsail_find_static_ip(aws, &static_ip_name)?.filter(|info| info.is_attached);
+        let public_ip = static_ip
+            .map(|info| info.ip_address)
+            .or_else(|| entry.public_ip.clone());
         println!(
             "name={} instance-id={} state={} public-ip={}",
-            entry.name, entry.name, entry.state, public_ip
+            entry.name,
+            entry.name,
+            entry.state,
+            public_ip.as_deref().unwrap_or("N/A")
         );
-    }
-
-    let ssh_entries = entries
-        .iter()
-        .map(|entry| InstanceEntry {
+        ssh_entries.push(InstanceEntry {
             name: Some(entry.name.clone()),
             instance_id: entry.name.clone(),
             state: entry.state.clone(),
-            public_ip: entry.public_ip.clone(),
-        })
-        .collect::<Vec<_>>();
+            public_ip,
+            region: Some(config.region.clone()),
+            spot: None,
+        });
+    }
 
     let identity_file = derive_private_key_path(&config.ssh_public_key_path);
     write_ssh_config(
@@ -1848,47 +4601,67 @@ fn run_gce_init(args: InitArgs, config_root: &Path) -> Result<()> {
     let config_path = config_dir.join(CONFIG_FILE_NAME);
     let ssh_config_path = config_dir.join(SSH_CONFIG_FILE);
 
-    if !config_path.exists() {
+    let already_existed = config_path.exists();
+    if already_existed && !args.wizard {
+        println!("exists {}", config_path.display());
+    } else {
+        if already_existed && !args.force {
+            bail!(
+                "config {} already exists; pass --force to overwrite with --wizard",
+                config_path.display()
+            );
+        }
         let defaults = load_global_config(config_root)?;
-        let project = defaults
+        let mut project = defaults
             .gce
             .as_ref()
             .and_then(|value| value.project.clone())
             .or_else(|| env::var("GOOGLE_CLOUD_PROJECT").ok())
             .or_else(|| env::var("GCLOUD_PROJECT").ok())
             .unwrap_or_default();
-        let zone = defaults
+        let mut zone = defaults
             .gce
             .as_ref()
             .and_then(|value| value.zone.clone())
             .or_else(|| env::var("CLOUDSDK_COMPUTE_ZONE").ok())
             .unwrap_or_else(|| "asia-northeast1-a".to_string());
-        let ssh_public_key_path = defaults
+        let mut ssh_public_key_path = defaults
             .gce
             .as_ref()
             .and_then(|value| value.ssh_public_key_path.clone())
             .unwrap_or_else(|| default_ssh_public_key_path(config_root));
-        let machine_type = defaults
+        let mut machine_type = defaults
             .gce
             .as_ref()
             .and_then(|value| value.default_machine_type.clone())
             .unwrap_or_else(|| DEFAULT_GCE_MACHINE_TYPE.to_string());
-        let image_family = defaults
+        let mut image_family = defaults
             .gce
             .as_ref()
             .and_then(|value| value.image_family.clone())
             .unwrap_or_else(|| DEFAULT_GCE_IMAGE_FAMILY.to_string());
-        let image_project = defaults
+        let mut image_project = defaults
             .gce
             .as_ref()
             .and_then(|value| value.image_project.clone())
             .unwrap_or_else(|| DEFAULT_GCE_IMAGE_PROJECT.to_string());
-        let ssh_user = defaults
+        let mut ssh_user = defaults
             .gce
             .as_ref()
             .and_then(|value| value.ssh_user.clone())
             .unwrap_or_else(|| DEFAULT_GCE_SSH_USER.to_string());
 
+        if args.wizard {
+            println!("configuring gce cluster '{}'", args.cluster);
+            project = prompt_wizard_field("project", &project, &[])?;
+            zone = prompt_wizard_field("zone", &zone, &gce_zone_choices())?;
+            ssh_public_key_path = prompt_wizard_public_key_path(&ssh_public_key_path)?;
+            machine_type = prompt_wizard_field("default_machine_type", &machine_type, &[])?;
+            image_family = prompt_wizard_field("image_family", &image_family, &[])?;
+            image_project = prompt_wizard_field("image_project", &image_project, &[])?;
+            ssh_user = prompt_wizard_field("ssh_user", &ssh_user, &[])?;
+        }
+
         let contents = default_gce_config_contents(
             &args.cluster,
             &project,
@@ -1899,11 +4672,12 @@ fn run_gce_init(args: InitArgs, config_root: &Path) -> Result<()> {
             &image_project,
             &ssh_user,
         );
-        fs::write(&config_path, contents)
-            .with_context(|| format!("write {}", config_path.display()))?;
-        println!("created {}", config_path.display());
-    } else {
-        println!("exists {}", config_path.display());
+        write_config_secured(&config_path, &contents)?;
+        println!(
+            "{} {}",
+            if already_existed { "updated" } else { "created" },
+            config_path.display()
+        );
     }
 
     if !ssh_config_path.exists() {
@@ -1952,7 +4726,7 @@ fn run_gce_up(args: GceUpArgs, config_root: &Path) -> Result<()> {
         gce_cluster_label_value(&config.cluster_name)
     );
 
-    let create_args = vec![
+    let mut create_args = vec![
         "compute".to_string(),
         "instances".to_string(),
         "create".to_string(),
@@ -1972,6 +4746,19 @@ fn run_gce_up(args: GceUpArgs, config_root: &Path) -> Result<()> {
         "--format".to_string(),
         "json".to_string(),
     ];
+
+    let user_data = resolve_user_data(args.user_data.as_deref(), args.user_data_inline.as_deref())?
+        .or_else(|| config.user_data.clone());
+    let user_data = merge_ssh_authorized_keys(user_data, &args.ssh_key)?;
+    if let Some(user_data) = user_data.as_deref() {
+        let cluster_dir = gce_cluster_dir(config_root, &args.cluster)?;
+        let user_data_path = cluster_dir.join("user-data");
+        fs::write(&user_data_path, user_data)
+            .with_context(|| format!("write {}", user_data_path.display()))?;
+        create_args.push("--metadata-from-file".to_string());
+        create_args.push(format!("user-data={}", user_data_path.display()));
+    }
+
     let _ = gcloud.run(&create_args)?;
 
     gce_wait_for_instance_state(&gcloud, &config.cluster_name, &args.name, "RUNNING")?;
@@ -1995,6 +4782,64 @@ fn run_gce_status(args: StatusArgs, config_root: &Path) -> Result<()> {
     print_gce_status_and_refresh_ssh_config(&gcloud, &config)
 }
 
+fn run_gce_config(args: StatusArgs, config_root: &Path) -> Result<()> {
+    let cluster_path = match args.config.as_deref() {
+        Some(path) => PathBuf::from(path),
+        None => gce_cluster_config_path(config_root, &args.cluster)?,
+    };
+    let (_, figment) =
+        load_layered_cluster_config(config_root, GCE_PROVIDER, &args.cluster, &cluster_path)?;
+    let config = load_gce_config(config_root, &args.cluster, args.config.as_deref())?;
+
+    println!("provider=gce cluster={}", args.cluster);
+    print_config_field(
+        "project",
+        &config.project,
+        config_field_source(&figment, &cluster_path, GCE_PROVIDER, "project"),
+    );
+    print_config_field(
+        "zone",
+        &config.zone,
+        config_field_source(&figment, &cluster_path, GCE_PROVIDER, "zone"),
+    );
+    print_config_field(
+        "ssh_public_key_path",
+        &config.ssh_public_key_path,
+        config_field_source(&figment, &cluster_path, GCE_PROVIDER, "ssh_public_key_path"),
+    );
+    print_config_field(
+        "default_machine_type",
+        &config.default_machine_type,
+        config_field_source(
+            &figment,
+            &cluster_path,
+            GCE_PROVIDER,
+            "default_machine_type",
+        ),
+    );
+    print_config_field(
+        "image_family",
+        &config.image_family,
+        config_field_source(&figment, &cluster_path, GCE_PROVIDER, "image_family"),
+    );
+    print_config_field(
+        "image_project",
+        &config.image_project,
+        config_field_source(&figment, &cluster_path, GCE_PROVIDER, "image_project"),
+    );
+    print_config_field(
+        "ssh_user",
+        &config.ssh_user,
+        config_field_source(&figment, &cluster_path, GCE_PROVIDER, "ssh_user"),
+    );
+    print_optional_config_field(
+        "user_data",
+        config.user_data.as_deref(),
+        config_field_source(&figment, &cluster_path, GCE_PROVIDER, "user_data"),
+    );
+    Ok(())
+}
+
 fn run_gce_health(args: HealthArgs, config_root: &Path) -> Result<()> {
     check_gcloud_cli()?;
     let config = load_gce_config(config_root, &args.cluster, args.config.as_deref())?;
@@ -2027,6 +4872,49 @@ fn run_gce_health(args: HealthArgs, config_root: &Path) -> Result<()> {
     Ok(())
 }
 
+fn run_gce_exec(args: ExecArgs, config_root: &Path) -> Result<()> {
+    ensure_vmcli_ssh_keypair(config_root)?;
+    check_gcloud_cli()?;
+    let config = load_gce_config(config_root, &args.cluster, args.config.as_deref())?;
+    let gcloud = GcloudCli::new(config.project.clone());
+
+    let identity_file = derive_private_key_path(&config.ssh_public_key_path);
+
+    match args.name.as_ref() {
+        Some(name) => {
+            let instance = gce_find_instance(&gcloud, &config.cluster_name, name)?
+                .ok_or_else(|| anyhow!("gce instance '{}' not found in cluster", name))?;
+            let public_ip = instance
+                .public_ip
+                .ok_or_else(|| anyhow!("gce instance '{}' has no public ip", name))?;
+            let exit_code = ssh_exec_command(
+                &public_ip,
+                &config.ssh_user,
+                &identity_file,
+                args.boot_timeout_secs,
+                &args.command,
+            )?;
+            std::process::exit(exit_code);
+        }
+        None => {
+            let instances = gce_list_cluster_instances(&gcloud, &config.cluster_name)?;
+            let targets = instances
+                .into_iter()
+                .map(|instance| (instance.name, instance.public_ip))
+                .collect();
+            let concurrency = args.concurrency.unwrap_or_else(default_concurrency);
+            fan_out_ssh_exec(
+                targets,
+                &config.ssh_user,
+                &identity_file,
+                args.boot_timeout_secs,
+                &args.command,
+                concurrency,
+            )
+        }
+    }
+}
+
 fn run_gce_reboot(args: RebootArgs, config_root: &Path) -> Result<()> {
     check_gcloud_cli()?;
     let config = load_gce_config(config_root, &args.cluster, args.config.as_deref())?;
@@ -2167,6 +5055,8 @@ fn print_gce_status_and_refresh_ssh_config(
             instance_id: instance.instance_id.clone(),
             state: instance.state.clone(),
             public_ip: instance.public_ip.clone(),
+            region: instance.zone.clone().or_else(|| Some(config.zone.clone())),
+            spot: None,
         })
         .collect::<Vec<_>>();
     let identity_file = derive_private_key_path(&config.ssh_public_key_path);
@@ -2297,40 +5187,84 @@ fn run_droplet_init(args: InitArgs, config_root: &Path) -> Result<()> {
     let config_path = config_dir.join(CONFIG_FILE_NAME);
     let ssh_config_path = config_dir.join(SSH_CONFIG_FILE);
 
-    if !config_path.exists() {
+    let run_interactive = args.interactive && io::stdin().is_terminal();
+    let already_existed = config_path.exists();
+    if already_existed && !args.wizard && !run_interactive {
+        println!("exists {}", config_path.display());
+    } else {
+        if already_existed && !args.force {
+            bail!(
+                "config {} already exists; pass --force to overwrite with --wizard",
+                config_path.display()
+            );
+        }
         let defaults = load_global_config(config_root)?;
-        let region = defaults
+        let mut region = defaults
             .droplet
             .as_ref()
             .and_then(|value| value.region.clone())
             .unwrap_or_else(|| "sfo3".to_string());
-        let ssh_public_key_path = defaults
+        let mut ssh_public_key_path = defaults
             .droplet
             .as_ref()
             .and_then(|value| value.ssh_public_key_path.clone())
             .unwrap_or_else(|| default_ssh_public_key_path(config_root));
-        let default_size = defaults
+        let mut default_size = defaults
             .droplet
             .as_ref()
             .and_then(|value| value.default_size.clone())
             .unwrap_or_else(|| DEFAULT_DROPLET_SIZE.to_string());
-        let image = defaults
+        let mut image = defaults
             .droplet
             .as_ref()
             .and_then(|value| value.image.clone())
             .unwrap_or_else(|| DEFAULT_DROPLET_IMAGE.to_string());
+        let mut ssh_key_fingerprint = String::new();
+
+        if run_interactive {
+            println!(
+                "configuring droplet cluster '{}' from doctl's live catalogs",
+                args.cluster
+            );
+            region = prompt_catalog_choice("region", &region, &droplet_region_choices())?;
+            ssh_public_key_path = prompt_wizard_public_key_path(&ssh_public_key_path)?;
+            default_size = prompt_catalog_choice("default_size", &default_size, &droplet_size_choices())?;
+            image = prompt_catalog_choice("image", &image, &droplet_image_choices())?;
+
+            let doctl = DoctlCli::new();
+            let fingerprint_config = DropletEffectiveConfig {
+                cluster_name: args.cluster.clone(),
+                region: region.clone(),
+                ssh_public_key_path: ssh_public_key_path.clone(),
+                default_size: default_size.clone(),
+                image: image.clone(),
+                ssh_key_fingerprint: None,
+                user_data: None,
+                ssh_config_path: ssh_config_path.clone(),
+            };
+            ssh_key_fingerprint = ensure_droplet_ssh_key_fingerprint(&doctl, &fingerprint_config)?;
+        } else if args.wizard {
+            println!("configuring droplet cluster '{}'", args.cluster);
+            region = prompt_wizard_field("region", &region, &droplet_region_choices())?;
+            ssh_public_key_path = prompt_wizard_public_key_path(&ssh_public_key_path)?;
+            default_size = prompt_wizard_field("default_size", &default_size, &[])?;
+            image = prompt_wizard_field("image", &image, &[])?;
+        }
+
         let contents = default_droplet_config_contents(
             &args.cluster,
             &region,
             &ssh_public_key_path,
             &default_size,
             &image,
+            &ssh_key_fingerprint,
+        );
+        write_config_secured(&config_path, &contents)?;
+        println!(
+            "{} {}",
+            if already_existed { "updated" } else { "created" },
+            config_path.display()
         );
-        fs::write(&config_path, contents)
-            .with_context(|| format!("write {}", config_path.display()))?;
-        println!("created {}", config_path.display());
-    } else {
-        println!("exists {}", config_path.display());
     }
 
     if !ssh_config_path.exists() {
@@ -2348,41 +5282,122 @@ fn run_droplet_up(args: DropletUpArgs, config_root: &Path) -> Result<()> {
     check_doctl_cli()?;
     let config = load_droplet_config(config_root, &args.cluster, args.config.as_deref())?;
     let doctl = DoctlCli::new();
-    let fingerprint = ensure_droplet_ssh_key_fingerprint(&doctl, &config)?;
 
-    if let Some(existing) = droplet_find_instance(&doctl, &config.cluster_name, &args.name)? {
-        if !existing.state.eq_ignore_ascii_case("off") {
-            bail!(
-                "droplet '{}' already exists in cluster '{}' (state={})",
-                args.name,
-                config.cluster_name,
-                existing.state
-            );
+    let journal_path = journal_path(config_root, DROPLET_PROVIDER, &config.cluster_name)?;
+    let mut journal = load_journal(&journal_path)?;
+    if !journal.activities.is_empty() && !args.resume {
+        bail!(
+            "found an interrupted provisioning journal for cluster '{}' at {}; rerun with --resume to continue it or delete the file to start over",
+            config.cluster_name,
+            journal_path.display()
+        );
+    }
+
+    if journal.activities.is_empty() {
+        if let Some(existing) = droplet_find_instance(&doctl, &config.cluster_name, &args.name)? {
+            if !existing.state.eq_ignore_ascii_case("off") {
+                bail!(
+                    "droplet '{}' already exists in cluster '{}' (state={})",
+                    args.name,
+                    config.cluster_name,
+                    existing.state
+                );
+            }
         }
     }
 
+    let fingerprint_output = run_journaled_activity(
+        &mut journal,
+        &journal_path,
+        "fingerprint",
+        &config.ssh_public_key_path,
+        || Ok(serde_json::json!(ensure_droplet_ssh_key_fingerprint(&doctl, &config)?)),
+    )?;
+    let fingerprint = fingerprint_output
+        .as_str()
+        .ok_or_else(|| anyhow!("journal activity 'fingerprint' has no cached string output"))?
+        .to_string();
+
     let size = args.size.unwrap_or_else(|| config.default_size.clone());
-    let create_args = vec![
-        "compute".to_string(),
-        "droplet".to_string(),
-        "create".to_string(),
-        args.name.clone(),
-        "--region".to_string(),
-        config.region.clone(),
-        "--size".to_string(),
-        size,
-        "--image".to_string(),
-        config.image.clone(),
-        "--tag-name".to_string(),
-        droplet_cluster_tag(&config.cluster_name),
-        "--ssh-keys".to_string(),
-        fingerprint,
-        "--output".to_string(),
-        "json".to_string(),
-    ];
-    let _ = doctl.run(&create_args)?;
+    let mut fingerprints = vec![fingerprint];
+    for (index, public_key_path) in args.ssh_key.iter().enumerate() {
+        let activity_name = format!("extra_fingerprint:{}", index);
+        let output = run_journaled_activity(
+            &mut journal,
+            &journal_path,
+            &activity_name,
+            public_key_path,
+            || {
+                Ok(serde_json::json!(ensure_droplet_extra_ssh_key_fingerprint(
+                    &doctl,
+                    &config,
+                    index,
+                    public_key_path,
+                )?))
+            },
+        )?;
+        let fingerprint = output
+            .as_str()
+            .ok_or_else(|| anyhow!("journal activity '{}' has no cached string output", activity_name))?
+            .to_string();
+        fingerprints.push(fingerprint);
+    }
+
+    let user_data = resolve_user_data(args.user_data.as_deref(), args.user_data_inline.as_deref())?
+        .or_else(|| config.user_data.clone());
+
+    run_journaled_activity(
+        &mut journal,
+        &journal_path,
+        "create",
+        &format!("{}:{}:{:?}", args.name, fingerprints.join(","), user_data),
+        || {
+            if let Some(existing) =
+                droplet_find_instance(&doctl, &config.cluster_name, &args.name)?
+            {
+                return Ok(serde_json::json!(existing.id));
+            }
+
+            let mut create_args = vec![
+                "compute".to_string(),
+                "droplet".to_string(),
+                "create".to_string(),
+                args.name.clone(),
+                "--region".to_string(),
+                config.region.clone(),
+                "--size".to_string(),
+                size.clone(),
+                "--image".to_string(),
+                config.image.clone(),
+                "--tag-name".to_string(),
+                droplet_cluster_tag(&config.cluster_name),
+                "--ssh-keys".to_string(),
+                fingerprints.join(","),
+                "--output".to_string(),
+                "json".to_string(),
+            ];
+            if let Some(user_data) = user_data.as_deref() {
+                create_args.push("--user-data".to_string());
+                create_args.push(user_data.to_string());
+            }
+            let _ = doctl.run(&create_args)?;
+            let created = droplet_find_instance(&doctl, &config.cluster_name, &args.name)?
+                .ok_or_else(|| anyhow!("droplet '{}' not found after create", args.name))?;
+            Ok(serde_json::json!(created.id))
+        },
+    )?;
+
+    run_journaled_activity(
+        &mut journal,
+        &journal_path,
+        "wait_active",
+        &args.name,
+        || {
+            droplet_wait_for_state(&doctl, &config.cluster_name, &args.name, "active")?;
+            Ok(serde_json::Value::Null)
+        },
+    )?;
 
-    droplet_wait_for_state(&doctl, &config.cluster_name, &args.name, "active")?;
     let created = droplet_find_instance(&doctl, &config.cluster_name, &args.name)?
         .ok_or_else(|| anyhow!("droplet '{}' not found after create", args.name))?;
     println!(
@@ -2392,7 +5407,21 @@ fn run_droplet_up(args: DropletUpArgs, config_root: &Path) -> Result<()> {
         created.public_ip.as_deref().unwrap_or("N/A")
     );
 
-    print_droplet_status_and_refresh_ssh_config(&doctl, &config)
+    if args.wait_ssh {
+        let public_ip = created
+            .public_ip
+            .as_deref()
+            .ok_or_else(|| anyhow!("droplet '{}' has no public ip to probe", created.name))?;
+        let identity_file = derive_private_key_path(&config.ssh_public_key_path);
+        run_journaled_activity(&mut journal, &journal_path, "wait_ssh", public_ip, || {
+            droplet_wait_for_ssh(public_ip, &identity_file)?;
+            Ok(serde_json::Value::Null)
+        })?;
+        println!("ssh-probe=ok");
+    }
+
+    print_droplet_status_and_refresh_ssh_config(&doctl, &config)?;
+    clear_journal(&journal_path)
 }
 
 fn run_droplet_status(args: StatusArgs, config_root: &Path) -> Result<()> {
@@ -2403,19 +5432,77 @@ fn run_droplet_status(args: StatusArgs, config_root: &Path) -> Result<()> {
     print_droplet_status_and_refresh_ssh_config(&doctl, &config)
 }
 
+fn run_droplet_config(args: StatusArgs, config_root: &Path) -> Result<()> {
+    let cluster_path = match args.config.as_deref() {
+        Some(path) => PathBuf::from(path),
+        None => droplet_cluster_config_path(config_root, &args.cluster)?,
+    };
+    let (_, figment) =
+        load_layered_cluster_config(config_root, DROPLET_PROVIDER, &args.cluster, &cluster_path)?;
+    let config = load_droplet_config(config_root, &args.cluster, args.config.as_deref())?;
+
+    println!("provider=droplet cluster={}", args.cluster);
+    print_config_field(
+        "region",
+        &config.region,
+        config_field_source(&figment, &cluster_path, DROPLET_PROVIDER, "region"),
+    );
+    print_config_field(
+        "ssh_public_key_path",
+        &config.ssh_public_key_path,
+        config_field_source(
+            &figment,
+            &cluster_path,
+            DROPLET_PROVIDER,
+            "ssh_public_key_path",
+        ),
+    );
+    print_config_field(
+        "default_size",
+        &config.default_size,
+        config_field_source(&figment, &cluster_path, DROPLET_PROVIDER, "default_size"),
+    );
+    print_config_field(
+        "image",
+        &config.image,
+        config_field_source(&figment, &cluster_path, DROPLET_PROVIDER, "image"),
+    );
+    print_optional_config_field(
+        "ssh_key_fingerprint",
+        config.ssh_key_fingerprint.as_deref(),
+        config_field_source(
+            &figment,
+            &cluster_path,
+            DROPLET_PROVIDER,
+            "ssh_key_fingerprint",
+        ),
+    );
+    print_optional_config_field(
+        "user_data",
+        config.user_data.as_deref(),
+        config_field_source(&figment, &cluster_path, DROPLET_PROVIDER, "user_data"),
+    );
+    Ok(())
+}
+
 fn run_droplet_health(args: HealthArgs, config_root: &Path) -> Result<()> {
+    ensure_vmcli_ssh_keypair(config_root)?;
     check_doctl_cli()?;
     let config = load_droplet_config(config_root, &args.cluster, args.config.as_deref())?;
     let doctl = DoctlCli::new();
     let droplet = droplet_find_instance(&doctl, &config.cluster_name, &args.name)?
         .ok_or_else(|| anyhow!("droplet '{}' not found in cluster", args.name))?;
     let state_lower = droplet.state.to_ascii_lowercase();
-    let (health_level, notes) = if state_lower == "active" && droplet.public_ip.is_some() {
-        ("ok", "instance-running")
-    } else if state_lower == "active" {
-        ("degraded", "running-without-public-ip")
+    let (health_level, notes) = if state_lower != "active" {
+        ("unreachable", "instance-not-running".to_string())
+    } else if let Some(public_ip) = droplet.public_ip.as_deref() {
+        let identity_file = derive_private_key_path(&config.ssh_public_key_path);
+        match droplet_ssh_probe(public_ip, &identity_file) {
+            Ok(()) => ("ok", "ssh-probe-succeeded".to_string()),
+            Err(err) => ("degraded", err.to_string()),
+        }
     } else {
-        ("unreachable", "instance-not-running")
+        ("degraded", "running-without-public-ip".to_string())
     };
 
     println!("provider=droplet");
@@ -2428,39 +5515,507 @@ fn run_droplet_health(args: HealthArgs, config_root: &Path) -> Result<()> {
         droplet.public_ip.as_deref().unwrap_or("N/A")
     );
     println!("health.level={}", health_level);
+    println!("health.notes={}", one_line_value(&notes));
+    Ok(())
+}
+
+fn run_droplet_exec(args: ExecArgs, config_root: &Path) -> Result<()> {
+    ensure_vmcli_ssh_keypair(config_root)?;
+    check_doctl_cli()?;
+    let config = load_droplet_config(config_root, &args.cluster, args.config.as_deref())?;
+    let doctl = DoctlCli::new();
+
+    let identity_file = derive_private_key_path(&config.ssh_public_key_path);
+
+    match args.name.as_ref() {
+        Some(name) => {
+            let droplet = droplet_find_instance(&doctl, &config.cluster_name, name)?
+                .ok_or_else(|| anyhow!("droplet '{}' not found in cluster", name))?;
+            let public_ip = droplet
+                .public_ip
+                .ok_or_else(|| anyhow!("droplet '{}' has no public ip", name))?;
+            let exit_code = ssh_exec_command(
+                &public_ip,
+                DEFAULT_INSTANCE_OS_USER,
+                &identity_file,
+                args.boot_timeout_secs,
+                &args.command,
+            )?;
+            std::process::exit(exit_code);
+        }
+        None => {
+            let droplets = droplet_list_cluster_instances(&doctl, &config.cluster_name)?;
+            let targets = droplets
+                .into_iter()
+                .map(|droplet| (droplet.name, droplet.public_ip))
+                .collect();
+            let concurrency = args.concurrency.unwrap_or_else(default_concurrency);
+            fan_out_ssh_exec(
+                targets,
+                DEFAULT_INSTANCE_OS_USER,
+                &identity_file,
+                args.boot_timeout_secs,
+                &args.command,
+                concurrency,
+            )
+        }
+    }
+}
+
+fn run_droplet_reboot(args: RebootArgs, config_root: &Path) -> Result<()> {
+    check_doctl_cli()?;
+    let config = load_droplet_config(config_root, &args.cluster, args.config.as_deref())?;
+    let doctl = DoctlCli::new();
+    let droplet = droplet_find_instance(&doctl, &config.cluster_name, &args.name)?
+        .ok_or_else(|| anyhow!("droplet '{}' not found in cluster", args.name))?;
+    let reboot_args = vec![
+        "compute".to_string(),
+        "droplet-action".to_string(),
+        "reboot".to_string(),
+        droplet.id.to_string(),
+        "--wait".to_string(),
+    ];
+    let _ = doctl.run(&reboot_args)?;
+    println!("rebooted name={} instance-id={}", droplet.name, droplet.id);
+    Ok(())
+}
+
+fn run_droplet_destroy(args: DestroyArgs, config_root: &Path) -> Result<()> {
+    check_doctl_cli()?;
+    let config = load_droplet_config(config_root, &args.cluster, args.config.as_deref())?;
+    let doctl = DoctlCli::new();
+    let droplet = droplet_find_instance(&doctl, &config.cluster_name, &args.name)?
+        .ok_or_else(|| anyhow!("droplet '{}' not found in cluster", args.name))?;
+
+    if !args.force {
+        let prompt = format!(
+            "Delete droplet '{}' in cluster '{}' ? [y/N]: ",
+            droplet.name, config.cluster_name
+        );
+        if !confirm(&prompt)? {
+            println!("aborted");
+            return Ok(());
+        }
+    }
+
+    let destroy_args = vec![
+        "compute".to_string(),
+        "droplet".to_string(),
+        "delete".to_string(),
+        droplet.id.to_string(),
+        "--force".to_string(),
+    ];
+    let _ = doctl.run(&destroy_args)?;
+    println!(
+        "terminated name={} instance-id={}",
+        droplet.name, droplet.id
+    );
+    print_droplet_status_and_refresh_ssh_config(&doctl, &config)
+}
+
+fn run_droplet_prune(args: PruneArgs, config_root: &Path) -> Result<()> {
+    check_doctl_cli()?;
+    let config = load_droplet_config(config_root, &args.cluster, args.config.as_deref())?;
+    let doctl = DoctlCli::new();
+    let droplets = droplet_list_cluster_instances(&doctl, &config.cluster_name)?;
+    if droplets.is_empty() {
+        println!("nothing to prune");
+        maybe_cleanup_provider_cluster_config(
+            config_root,
+            DROPLET_PROVIDER,
+            &config.cluster_name,
+            args.force,
+        )?;
+        return Ok(());
+    }
+
+    if !args.force {
+        let prompt = format!(
+            "Delete all droplets for cluster '{}' ({})? [y/N]: ",
+            config.cluster_name,
+            droplets.len()
+        );
+        if !confirm(&prompt)? {
+            println!("aborted");
+            return Ok(());
+        }
+    }
+
+    let journal_path = journal_path(config_root, DROPLET_PROVIDER, &config.cluster_name)?;
+    let mut journal = load_journal(&journal_path)?;
+
+    for droplet in &droplets {
+        let activity_name = format!("delete:{}", droplet.id);
+        run_journaled_activity(
+            &mut journal,
+            &journal_path,
+            &activity_name,
+            &droplet.id.to_string(),
+            || {
+                let destroy_args = vec![
+                    "compute".to_string(),
+                    "droplet".to_string(),
+                    "delete".to_string(),
+                    droplet.id.to_string(),
+                    "--force".to_string(),
+                ];
+                let _ = doctl.run(&destroy_args)?;
+                println!("deleted name={} instance-id={}", droplet.name, droplet.id);
+                Ok(serde_json::Value::Null)
+            },
+        )?;
+    }
+
+    print_droplet_status_and_refresh_ssh_config(&doctl, &config)?;
+    if droplet_list_cluster_instances(&doctl, &config.cluster_name)?.is_empty() {
+        clear_journal(&journal_path)?;
+        maybe_cleanup_provider_cluster_config(
+            config_root,
+            DROPLET_PROVIDER,
+            &config.cluster_name,
+            args.force,
+        )?;
+    }
+    Ok(())
+}
+
+fn run_openstack_init(args: InitArgs, config_root: &Path) -> Result<()> {
+    ensure_vmcli_ssh_keypair(config_root)?;
+    let config_dir = openstack_cluster_dir(config_root, &args.cluster)?;
+    fs::create_dir_all(&config_dir)
+        .with_context(|| format!("create config dir {}", config_dir.display()))?;
+
+    let config_path = config_dir.join(CONFIG_FILE_NAME);
+    let ssh_config_path = config_dir.join(SSH_CONFIG_FILE);
+
+    let already_existed = config_path.exists();
+    if already_existed && !args.wizard {
+        println!("exists {}", config_path.display());
+    } else {
+        if already_existed && !args.force {
+            bail!(
+                "config {} already exists; pass --force to overwrite with --wizard",
+                config_path.display()
+            );
+        }
+        let defaults = load_global_config(config_root)?;
+        let mut auth_url = defaults
+            .openstack
+            .as_ref()
+            .and_then(|value| value.auth_url.clone())
+            .unwrap_or_else(|| "http://localhost:5000/v3".to_string());
+        let mut project = defaults
+            .openstack
+            .as_ref()
+            .and_then(|value| value.project.clone())
+            .unwrap_or_else(|| "admin".to_string());
+        let mut region = defaults
+            .openstack
+            .as_ref()
+            .and_then(|value| value.region.clone())
+            .unwrap_or_else(|| "RegionOne".to_string());
+        let mut flavor = defaults
+            .openstack
+            .as_ref()
+            .and_then(|value| value.default_flavor.clone())
+            .unwrap_or_else(|| "m1.small".to_string());
+        let mut image = defaults
+            .openstack
+            .as_ref()
+            .and_then(|value| value.image.clone())
+            .unwrap_or_else(|| "ubuntu-24.04".to_string());
+        let mut network = defaults
+            .openstack
+            .as_ref()
+            .and_then(|value| value.network.clone())
+            .unwrap_or_else(|| "private".to_string());
+        let mut ssh_public_key_path = defaults
+            .openstack
+            .as_ref()
+            .and_then(|value| value.ssh_public_key_path.clone())
+            .unwrap_or_else(|| default_ssh_public_key_path(config_root));
+
+        if args.wizard {
+            println!("configuring openstack cluster '{}'", args.cluster);
+            auth_url = prompt_wizard_field("auth_url", &auth_url, &[])?;
+            project = prompt_wizard_field("project", &project, &[])?;
+            region = prompt_wizard_field("region", &region, &openstack_region_choices())?;
+            flavor = prompt_wizard_field("default_flavor", &flavor, &[])?;
+            image = prompt_wizard_field("image", &image, &[])?;
+            network = prompt_wizard_field("network", &network, &[])?;
+            ssh_public_key_path = prompt_wizard_public_key_path(&ssh_public_key_path)?;
+        }
+
+        let contents = default_openstack_config_contents(
+            &args.cluster,
+            &auth_url,
+            &project,
+            &region,
+            &flavor,
+            &image,
+            &network,
+            &ssh_public_key_path,
+        );
+        write_config_secured(&config_path, &contents)?;
+        println!(
+            "{} {}",
+            if already_existed { "updated" } else { "created" },
+            config_path.display()
+        );
+    }
+
+    if !ssh_config_path.exists() {
+        fs::write(&ssh_config_path, "")
+            .with_context(|| format!("write {}", ssh_config_path.display()))?;
+        println!("created {}", ssh_config_path.display());
+    } else {
+        println!("exists {}", ssh_config_path.display());
+    }
+    Ok(())
+}
+
+fn run_openstack_up(args: OpenstackUpArgs, config_root: &Path) -> Result<()> {
+    ensure_vmcli_ssh_keypair(config_root)?;
+    check_openstack_cli()?;
+    let config = load_openstack_config(config_root, &args.cluster, args.config.as_deref())?;
+    let openstack = OpenstackCli::new(
+        config.auth_url.clone(),
+        config.project.clone(),
+        config.region.clone(),
+    );
+    let keypair_name = ensure_openstack_keypair(&openstack, &config)?;
+
+    if openstack_find_instance(&openstack, &config.cluster_name, &args.name)?.is_some() {
+        bail!(
+            "instance '{}' already exists in cluster '{}'",
+            args.name,
+            config.cluster_name
+        );
+    }
+
+    let flavor = args.flavor.unwrap_or_else(|| config.default_flavor.clone());
+    let create_args = vec![
+        "server".to_string(),
+        "create".to_string(),
+        "--flavor".to_string(),
+        flavor,
+        "--image".to_string(),
+        config.image.clone(),
+        "--network".to_string(),
+        config.network.clone(),
+        "--key-name".to_string(),
+        keypair_name,
+        "--tag".to_string(),
+        openstack_cluster_tag(&config.cluster_name),
+        "-f".to_string(),
+        "json".to_string(),
+        args.name.clone(),
+    ];
+    let _ = openstack.run(&create_args)?;
+
+    openstack_wait_for_state(&openstack, &config.cluster_name, &args.name, "ACTIVE")?;
+    let created = openstack_find_instance(&openstack, &config.cluster_name, &args.name)?
+        .ok_or_else(|| anyhow!("instance '{}' not found after create", args.name))?;
+    println!(
+        "name={} instance-id={} public-ip={}",
+        created.name,
+        created.id,
+        created.public_ip.as_deref().unwrap_or("N/A")
+    );
+
+    print_openstack_status_and_refresh_ssh_config(&openstack, &config)
+}
+
+fn run_openstack_status(args: StatusArgs, config_root: &Path) -> Result<()> {
+    ensure_vmcli_ssh_keypair(config_root)?;
+    check_openstack_cli()?;
+    let config = load_openstack_config(config_root, &args.cluster, args.config.as_deref())?;
+    let openstack = OpenstackCli::new(
+        config.auth_url.clone(),
+        config.project.clone(),
+        config.region.clone(),
+    );
+    print_openstack_status_and_refresh_ssh_config(&openstack, &config)
+}
+
+fn run_openstack_config(args: StatusArgs, config_root: &Path) -> Result<()> {
+    let cluster_path = match args.config.as_deref() {
+        Some(path) => PathBuf::from(path),
+        None => openstack_cluster_config_path(config_root, &args.cluster)?,
+    };
+    let (_, figment) = load_layered_cluster_config(
+        config_root,
+        OPENSTACK_PROVIDER,
+        &args.cluster,
+        &cluster_path,
+    )?;
+    let config = load_openstack_config(config_root, &args.cluster, args.config.as_deref())?;
+
+    println!("provider=openstack cluster={}", args.cluster);
+    print_config_field(
+        "auth_url",
+        &config.auth_url,
+        config_field_source(&figment, &cluster_path, OPENSTACK_PROVIDER, "auth_url"),
+    );
+    print_config_field(
+        "project",
+        &config.project,
+        config_field_source(&figment, &cluster_path, OPENSTACK_PROVIDER, "project"),
+    );
+    print_config_field(
+        "region",
+        &config.region,
+        config_field_source(&figment, &cluster_path, OPENSTACK_PROVIDER, "region"),
+    );
+    print_config_field(
+        "default_flavor",
+        &config.default_flavor,
+        config_field_source(&figment, &cluster_path, OPENSTACK_PROVIDER, "default_flavor"),
+    );
+    print_config_field(
+        "image",
+        &config.image,
+        config_field_source(&figment, &cluster_path, OPENSTACK_PROVIDER, "image"),
+    );
+    print_config_field(
+        "network",
+        &config.network,
+        config_field_source(&figment, &cluster_path, OPENSTACK_PROVIDER, "network"),
+    );
+    print_config_field(
+        "ssh_public_key_path",
+        &config.ssh_public_key_path,
+        config_field_source(
+            &figment,
+            &cluster_path,
+            OPENSTACK_PROVIDER,
+            "ssh_public_key_path",
+        ),
+    );
+    print_config_field(
+        "keypair_name",
+        &config.keypair_name,
+        config_field_source(&figment, &cluster_path, OPENSTACK_PROVIDER, "keypair_name"),
+    );
+    Ok(())
+}
+
+fn run_openstack_health(args: HealthArgs, config_root: &Path) -> Result<()> {
+    check_openstack_cli()?;
+    let config = load_openstack_config(config_root, &args.cluster, args.config.as_deref())?;
+    let openstack = OpenstackCli::new(
+        config.auth_url.clone(),
+        config.project.clone(),
+        config.region.clone(),
+    );
+    let instance = openstack_find_instance(&openstack, &config.cluster_name, &args.name)?
+        .ok_or_else(|| anyhow!("instance '{}' not found in cluster", args.name))?;
+    let state_upper = instance.state.to_ascii_uppercase();
+    let (health_level, notes) = if state_upper == "ACTIVE" && instance.public_ip.is_some() {
+        ("ok", "instance-running")
+    } else if state_upper == "ACTIVE" {
+        ("degraded", "running-without-public-ip")
+    } else {
+        ("unreachable", "instance-not-running")
+    };
+
+    println!("provider=openstack");
+    println!("cluster={}", config.cluster_name);
+    println!("name={}", instance.name);
+    println!("instance.id={}", instance.id);
+    println!("instance.state={}", instance.state);
+    println!(
+        "instance.public-ip={}",
+        instance.public_ip.as_deref().unwrap_or("N/A")
+    );
+    println!("health.level={}", health_level);
     println!("health.notes={}", notes);
     Ok(())
 }
 
-fn run_droplet_reboot(args: RebootArgs, config_root: &Path) -> Result<()> {
-    check_doctl_cli()?;
-    let config = load_droplet_config(config_root, &args.cluster, args.config.as_deref())?;
-    let doctl = DoctlCli::new();
-    let droplet = droplet_find_instance(&doctl, &config.cluster_name, &args.name)?
-        .ok_or_else(|| anyhow!("droplet '{}' not found in cluster", args.name))?;
+fn run_openstack_exec(args: ExecArgs, config_root: &Path) -> Result<()> {
+    ensure_vmcli_ssh_keypair(config_root)?;
+    check_openstack_cli()?;
+    let config = load_openstack_config(config_root, &args.cluster, args.config.as_deref())?;
+    let openstack = OpenstackCli::new(
+        config.auth_url.clone(),
+        config.project.clone(),
+        config.region.clone(),
+    );
+
+    let identity_file = derive_private_key_path(&config.ssh_public_key_path);
+
+    match args.name.as_ref() {
+        Some(name) => {
+            let instance = openstack_find_instance(&openstack, &config.cluster_name, name)?
+                .ok_or_else(|| anyhow!("instance '{}' not found in cluster", name))?;
+            let public_ip = instance
+                .public_ip
+                .ok_or_else(|| anyhow!("instance '{}' has no public ip", name))?;
+            let exit_code = ssh_exec_command(
+                &public_ip,
+                DEFAULT_INSTANCE_OS_USER,
+                &identity_file,
+                args.boot_timeout_secs,
+                &args.command,
+            )?;
+            std::process::exit(exit_code);
+        }
+        None => {
+            let instances = openstack_list_cluster_instances(&openstack, &config.cluster_name)?;
+            let targets = instances
+                .into_iter()
+                .map(|instance| (instance.name, instance.public_ip))
+                .collect();
+            let concurrency = args.concurrency.unwrap_or_else(default_concurrency);
+            fan_out_ssh_exec(
+                targets,
+                DEFAULT_INSTANCE_OS_USER,
+                &identity_file,
+                args.boot_timeout_secs,
+                &args.command,
+                concurrency,
+            )
+        }
+    }
+}
+
+fn run_openstack_reboot(args: RebootArgs, config_root: &Path) -> Result<()> {
+    check_openstack_cli()?;
+    let config = load_openstack_config(config_root, &args.cluster, args.config.as_deref())?;
+    let openstack = OpenstackCli::new(
+        config.auth_url.clone(),
+        config.project.clone(),
+        config.region.clone(),
+    );
+    let instance = openstack_find_instance(&openstack, &config.cluster_name, &args.name)?
+        .ok_or_else(|| anyhow!("instance '{}' not found in cluster", args.name))?;
     let reboot_args = vec![
-        "compute".to_string(),
-        "droplet-action".to_string(),
+        "server".to_string(),
         "reboot".to_string(),
-        droplet.id.to_string(),
-        "--wait".to_string(),
+        "--hard".to_string(),
+        instance.id.clone(),
     ];
-    let _ = doctl.run(&reboot_args)?;
-    println!("rebooted name={} instance-id={}", droplet.name, droplet.id);
+    let _ = openstack.run(&reboot_args)?;
+    println!("rebooted name={} instance-id={}", instance.name, instance.id);
     Ok(())
 }
 
-fn run_droplet_destroy(args: DestroyArgs, config_root: &Path) -> Result<()> {
-    check_doctl_cli()?;
-    let config = load_droplet_config(config_root, &args.cluster, args.config.as_deref())?;
-    let doctl = DoctlCli::new();
-    let droplet = droplet_find_instance(&doctl, &config.cluster_name, &args.name)?
-        .ok_or_else(|| anyhow!("droplet '{}' not found in cluster", args.name))?;
+fn run_openstack_destroy(args: DestroyArgs, config_root: &Path) -> Result<()> {
+    check_openstack_cli()?;
+    let config = load_openstack_config(config_root, &args.cluster, args.config.as_deref())?;
+    let openstack = OpenstackCli::new(
+        config.auth_url.clone(),
+        config.project.clone(),
+        config.region.clone(),
+    );
+    let instance = openstack_find_instance(&openstack, &config.cluster_name, &args.name)?
+        .ok_or_else(|| anyhow!("instance '{}' not found in cluster", args.name))?;
 
     if !args.force {
         let prompt = format!(
-            "Delete droplet '{}' in cluster '{}' ? [y/N]: ",
-            droplet.name, config.cluster_name
+            "Delete instance '{}' in cluster '{}' ? [y/N]: ",
+            instance.name, config.cluster_name
         );
         if !confirm(&prompt)? {
             println!("aborted");
@@ -2468,31 +6023,29 @@ fn run_droplet_destroy(args: DestroyArgs, config_root: &Path) -> Result<()> {
         }
     }
 
-    let destroy_args = vec![
-        "compute".to_string(),
-        "droplet".to_string(),
-        "delete".to_string(),
-        droplet.id.to_string(),
-        "--force".to_string(),
-    ];
-    let _ = doctl.run(&destroy_args)?;
+    let destroy_args = vec!["server".to_string(), "delete".to_string(), instance.id.clone()];
+    let _ = openstack.run(&destroy_args)?;
     println!(
         "terminated name={} instance-id={}",
-        droplet.name, droplet.id
+        instance.name, instance.id
     );
-    print_droplet_status_and_refresh_ssh_config(&doctl, &config)
+    print_openstack_status_and_refresh_ssh_config(&openstack, &config)
 }
 
-fn run_droplet_prune(args: PruneArgs, config_root: &Path) -> Result<()> {
-    check_doctl_cli()?;
-    let config = load_droplet_config(config_root, &args.cluster, args.config.as_deref())?;
-    let doctl = DoctlCli::new();
-    let droplets = droplet_list_cluster_instances(&doctl, &config.cluster_name)?;
-    if droplets.is_empty() {
+fn run_openstack_prune(args: PruneArgs, config_root: &Path) -> Result<()> {
+    check_openstack_cli()?;
+    let config = load_openstack_config(config_root, &args.cluster, args.config.as_deref())?;
+    let openstack = OpenstackCli::new(
+        config.auth_url.clone(),
+        config.project.clone(),
+        config.region.clone(),
+    );
+    let instances = openstack_list_cluster_instances(&openstack, &config.cluster_name)?;
+    if instances.is_empty() {
         println!("nothing to prune");
         maybe_cleanup_provider_cluster_config(
             config_root,
-            DROPLET_PROVIDER,
+            OPENSTACK_PROVIDER,
             &config.cluster_name,
             args.force,
         )?;
@@ -2501,9 +6054,9 @@ fn run_droplet_prune(args: PruneArgs, config_root: &Path) -> Result<()> {
 
     if !args.force {
         let prompt = format!(
-            "Delete all droplets for cluster '{}' ({})? [y/N]: ",
+            "Delete all instances for cluster '{}' ({})? [y/N]: ",
             config.cluster_name,
-            droplets.len()
+            instances.len()
         );
         if !confirm(&prompt)? {
             println!("aborted");
@@ -2511,23 +6064,17 @@ fn run_droplet_prune(args: PruneArgs, config_root: &Path) -> Result<()> {
         }
     }
 
-    for droplet in &droplets {
-        let destroy_args = vec![
-            "compute".to_string(),
-            "droplet".to_string(),
-            "delete".to_string(),
-            droplet.id.to_string(),
-            "--force".to_string(),
-        ];
-        let _ = doctl.run(&destroy_args)?;
-        println!("deleted name={} instance-id={}", droplet.name, droplet.id);
+    for instance in &instances {
+        let destroy_args = vec!["server".to_string(), "delete".to_string(), instance.id.clone()];
+        let _ = openstack.run(&destroy_args)?;
+        println!("deleted name={} instance-id={}", instance.name, instance.id);
     }
 
-    print_droplet_status_and_refresh_ssh_config(&doctl, &config)?;
-    if droplet_list_cluster_instances(&doctl, &config.cluster_name)?.is_empty() {
+    print_openstack_status_and_refresh_ssh_config(&openstack, &config)?;
+    if openstack_list_cluster_instances(&openstack, &config.cluster_name)?.is_empty() {
         maybe_cleanup_provider_cluster_config(
             config_root,
-            DROPLET_PROVIDER,
+            OPENSTACK_PROVIDER,
             &config.cluster_name,
             args.force,
         )?;
@@ -2560,6 +6107,8 @@ fn print_droplet_status_and_refresh_ssh_config(
             instance_id: droplet.id.to_string(),
             state: droplet.state.clone(),
             public_ip: droplet.public_ip.clone(),
+            region: droplet.region.clone().or_else(|| Some(config.region.clone())),
+            spot: None,
         })
         .collect::<Vec<_>>();
     let identity_file = derive_private_key_path(&config.ssh_public_key_path);
@@ -2726,10 +6275,237 @@ fn ensure_droplet_ssh_key_fingerprint(
     bail!("unable to resolve imported droplet ssh key fingerprint")
 }
 
+fn ensure_droplet_extra_ssh_key_fingerprint(
+    doctl: &DoctlCli,
+    config: &DropletEffectiveConfig,
+    index: usize,
+    public_key_path: &str,
+) -> Result<String> {
+    let key_name = format!(
+        "vmcli-{}-extra-{}-key",
+        sanitize_cloud_identifier(&config.cluster_name),
+        index + 1
+    );
+    let list_args = vec![
+        "compute".to_string(),
+        "ssh-key".to_string(),
+        "list".to_string(),
+        "--output".to_string(),
+        "json".to_string(),
+    ];
+    let payload = doctl.run_json(&list_args)?;
+    let list = payload.as_array().cloned().unwrap_or_default();
+    for item in list {
+        let name = item
+            .get("name")
+            .and_then(|value| value.as_str())
+            .unwrap_or("");
+        let fingerprint = item
+            .get("fingerprint")
+            .and_then(|value| value.as_str())
+            .unwrap_or("");
+        if name == key_name && !fingerprint.is_empty() {
+            return Ok(fingerprint.to_string());
+        }
+    }
+
+    let import_args = vec![
+        "compute".to_string(),
+        "ssh-key".to_string(),
+        "import".to_string(),
+        key_name,
+        "--public-key-file".to_string(),
+        public_key_path.to_string(),
+        "--output".to_string(),
+        "json".to_string(),
+    ];
+    let payload = doctl.run_json(&import_args)?;
+    if let Some(fingerprint) = payload.get("fingerprint").and_then(|value| value.as_str()) {
+        return Ok(fingerprint.to_string());
+    }
+    if let Some(array) = payload.as_array() {
+        for item in array {
+            if let Some(fingerprint) = item.get("fingerprint").and_then(|value| value.as_str()) {
+                return Ok(fingerprint.to_string());
+            }
+        }
+    }
+    bail!("unable to resolve imported droplet ssh key fingerprint for {}", public_key_path)
+}
+
 fn droplet_cluster_tag(cluster: &str) -> String {
     format!("cluster-{}", sanitize_cloud_identifier(cluster))
 }
 
+fn print_openstack_status_and_refresh_ssh_config(
+    openstack: &OpenstackCli,
+    config: &OpenstackEffectiveConfig,
+) -> Result<()> {
+    let instances = openstack_list_cluster_instances(openstack, &config.cluster_name)?;
+    println!("region={}", config.region);
+    for instance in &instances {
+        let public_ip = instance.public_ip.as_deref().unwrap_or("N/A");
+        println!(
+            "name={} instance-id={} state={} public-ip={}",
+            instance.name, instance.id, instance.state, public_ip
+        );
+    }
+
+    let ssh_entries = instances
+        .iter()
+        .map(|instance| InstanceEntry {
+            name: Some(instance.name.clone()),
+            instance_id: instance.id.clone(),
+            state: instance.state.clone(),
+            public_ip: instance.public_ip.clone(),
+            region: Some(config.region.clone()),
+            spot: None,
+        })
+        .collect::<Vec<_>>();
+    let identity_file = derive_private_key_path(&config.ssh_public_key_path);
+    write_ssh_config(
+        &config.ssh_config_path,
+        &ssh_entries,
+        Some(&config.region),
+        None,
+        &identity_file,
+    )
+}
+
+fn openstack_wait_for_state(
+    openstack: &OpenstackCli,
+    cluster: &str,
+    name: &str,
+    expected_state: &str,
+) -> Result<()> {
+    for _ in 0..60 {
+        if let Some(instance) = openstack_find_instance(openstack, cluster, name)? {
+            if instance.state.eq_ignore_ascii_case(expected_state) {
+                return Ok(());
+            }
+        }
+        sleep(Duration::from_secs(5));
+    }
+    bail!(
+        "timeout waiting for instance '{}' to become {}",
+        name,
+        expected_state
+    );
+}
+
+fn openstack_find_instance(
+    openstack: &OpenstackCli,
+    cluster: &str,
+    name: &str,
+) -> Result<Option<OpenstackInstanceInfo>> {
+    let instances = openstack_list_cluster_instances(openstack, cluster)?;
+    let found = instances.into_iter().find(|instance| instance.name == name);
+    Ok(found)
+}
+
+fn openstack_list_cluster_instances(
+    openstack: &OpenstackCli,
+    cluster: &str,
+) -> Result<Vec<OpenstackInstanceInfo>> {
+    let args = vec![
+        "server".to_string(),
+        "list".to_string(),
+        "--tags".to_string(),
+        openstack_cluster_tag(cluster),
+        "-f".to_string(),
+        "json".to_string(),
+    ];
+    let payload = openstack.run_json(&args)?;
+    let list = payload.as_array().cloned().unwrap_or_default();
+    let mut instances = Vec::new();
+    for item in list {
+        let Some(id) = item.get("ID").and_then(|value| value.as_str()) else {
+            continue;
+        };
+        let Some(name) = item.get("Name").and_then(|value| value.as_str()) else {
+            continue;
+        };
+        let state = item
+            .get("Status")
+            .and_then(|value| value.as_str())
+            .unwrap_or("UNKNOWN")
+            .to_string();
+        let public_ip = openstack_public_ip(&item);
+        instances.push(OpenstackInstanceInfo {
+            id: id.to_string(),
+            name: name.to_string(),
+            state,
+            public_ip,
+        });
+    }
+    instances.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(instances)
+}
+
+fn openstack_public_ip(item: &serde_json::Value) -> Option<String> {
+    let networks = item.get("Networks")?.as_str()?;
+    for segment in networks.split(';') {
+        let Some((_, addrs)) = segment.split_once('=') else {
+            continue;
+        };
+        for addr in addrs.split(',') {
+            let addr = addr.trim();
+            if addr.is_empty() {
+                continue;
+            }
+            if !addr.starts_with("10.")
+                && !addr.starts_with("192.168.")
+                && !addr.starts_with("172.")
+            {
+                return Some(addr.to_string());
+            }
+        }
+    }
+    None
+}
+
+fn ensure_openstack_keypair(
+    openstack: &OpenstackCli,
+    config: &OpenstackEffectiveConfig,
+) -> Result<String> {
+    if !config.keypair_name.is_empty() {
+        return Ok(config.keypair_name.clone());
+    }
+
+    let key_name = format!(
+        "vmcli-{}-key",
+        sanitize_cloud_identifier(&config.cluster_name)
+    );
+    let list_args = vec![
+        "keypair".to_string(),
+        "list".to_string(),
+        "-f".to_string(),
+        "json".to_string(),
+    ];
+    let payload = openstack.run_json(&list_args)?;
+    let list = payload.as_array().cloned().unwrap_or_default();
+    for item in list {
+        let name = item.get("Name").and_then(|value| value.as_str()).unwrap_or("");
+        if name == key_name {
+            return Ok(key_name);
+        }
+    }
+
+    let create_args = vec![
+        "keypair".to_string(),
+        "create".to_string(),
+        "--public-key".to_string(),
+        config.ssh_public_key_path.clone(),
+        key_name.clone(),
+    ];
+    let _ = openstack.run(&create_args)?;
+    Ok(key_name)
+}
+
+fn openstack_cluster_tag(cluster: &str) -> String {
+    format!("cluster-{}", sanitize_cloud_identifier(cluster))
+}
+
 fn sanitize_cloud_identifier(input: &str) -> String {
     let mut out = String::with_capacity(input.len());
     for ch in input.chars() {
@@ -2940,7 +6716,107 @@ fn maybe_cleanup_provider_cluster_config(
             cluster
         );
     }
-    Ok(())
+    Ok(())
+}
+
+/// Status of a single step recorded in a [`ProvisionJournal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum JournalStatus {
+    Pending,
+    Completed,
+}
+
+/// One durable-workflow-style "activity": an input hash (so a changed input
+/// is never mistaken for a cached rerun) plus whatever output the step
+/// produced, so a resumed run can reuse it instead of repeating side effects.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct JournalActivity {
+    status: JournalStatus,
+    input_hash: u64,
+    output: Option<serde_json::Value>,
+}
+
+/// Small activity journal persisted as `journal.json` under a cluster's
+/// config dir. Lets a multi-step, partially-irreversible operation (provision
+/// a droplet, delete a batch of droplets) survive being killed mid-way:
+/// completed activities are skipped and their outputs reused on `--resume`
+/// instead of being re-executed against the cloud.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ProvisionJournal {
+    activities: std::collections::HashMap<String, JournalActivity>,
+}
+
+fn journal_input_hash(input: &str) -> u64 {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    let mut hasher = DefaultHasher::new();
+    input.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn journal_path(config_root: &Path, provider: &str, cluster: &str) -> Result<PathBuf> {
+    Ok(provider_cluster_dir(config_root, provider, cluster)?.join(JOURNAL_FILE_NAME))
+}
+
+fn load_journal(path: &Path) -> Result<ProvisionJournal> {
+    if !path.exists() {
+        return Ok(ProvisionJournal::default());
+    }
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("read journal {}", path.display()))?;
+    serde_json::from_str(&contents).with_context(|| format!("parse journal {}", path.display()))
+}
+
+fn save_journal(path: &Path, journal: &ProvisionJournal) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("create config dir {}", parent.display()))?;
+    }
+    let contents = serde_json::to_string_pretty(journal).context("serialize journal")?;
+    fs::write(path, contents).with_context(|| format!("write journal {}", path.display()))
+}
+
+fn clear_journal(path: &Path) -> Result<()> {
+    if path.exists() {
+        fs::remove_file(path).with_context(|| format!("remove journal {}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// Runs one journaled activity: if `name` is already recorded as `Completed`
+/// with a matching `input_hash`, its cached output is returned without
+/// calling `work`. Otherwise `work` is run, and on success its output is
+/// recorded as `Completed` and the journal is saved immediately, so a kill
+/// between two activities never loses progress already made.
+fn run_journaled_activity<F>(
+    journal: &mut ProvisionJournal,
+    path: &Path,
+    name: &str,
+    input: &str,
+    work: F,
+) -> Result<serde_json::Value>
+where
+    F: FnOnce() -> Result<serde_json::Value>,
+{
+    let input_hash = journal_input_hash(input);
+    if let Some(activity) = journal.activities.get(name) {
+        if activity.status == JournalStatus::Completed && activity.input_hash == input_hash {
+            return Ok(activity.output.clone().unwrap_or(serde_json::Value::Null));
+        }
+    }
+
+    let output = work()?;
+    journal.activities.insert(
+        name.to_string(),
+        JournalActivity {
+            status: JournalStatus::Completed,
+            input_hash,
+            output: Some(output.clone()),
+        },
+    );
+    save_journal(path, journal)?;
+    Ok(output)
 }
 
 fn ec2_cluster_dir(config_root: &Path, cluster: &str) -> Result<PathBuf> {
@@ -2991,6 +6867,18 @@ fn droplet_cluster_ssh_config_path(config_root: &Path, cluster: &str) -> Result<
     provider_cluster_ssh_config_path(config_root, DROPLET_PROVIDER, cluster)
 }
 
+fn openstack_cluster_dir(config_root: &Path, cluster: &str) -> Result<PathBuf> {
+    provider_cluster_dir(config_root, OPENSTACK_PROVIDER, cluster)
+}
+
+fn openstack_cluster_config_path(config_root: &Path, cluster: &str) -> Result<PathBuf> {
+    provider_cluster_config_path(config_root, OPENSTACK_PROVIDER, cluster)
+}
+
+fn openstack_cluster_ssh_config_path(config_root: &Path, cluster: &str) -> Result<PathBuf> {
+    provider_cluster_ssh_config_path(config_root, OPENSTACK_PROVIDER, cluster)
+}
+
 fn default_ec2_config_contents(
     cluster: &str,
     region: &str,
@@ -2998,7 +6886,7 @@ fn default_ec2_config_contents(
     default_instance_type: &str,
 ) -> String {
     format!(
-        "cluster_name = \"{}\"\n\n[ec2]\nregion = \"{}\"\nssh_public_key_path = \"{}\"\ndefault_instance_type = \"{}\"\nami_id = \"\"\n",
+        "cluster_name = \"{}\"\n\n[ec2]\nregion = \"{}\"\nssh_public_key_path = \"{}\"\ndefault_instance_type = \"{}\"\nami_id = \"\"\nuser_data = \"\"\n",
         cluster,
         region,
         ssh_public_key_path,
@@ -3015,7 +6903,7 @@ fn default_lightsail_config_contents(
     blueprint_id: &str,
 ) -> String {
     format!(
-        "cluster_name = \"{}\"\n\n[lightsail]\nregion = \"{}\"\nssh_public_key_path = \"{}\"\navailability_zone = \"{}\"\ndefault_bundle_id = \"{}\"\nblueprint_id = \"{}\"\nkey_pair_name = \"\"\n",
+        "cluster_name = \"{}\"\n\n[lightsail]\nregion = \"{}\"\nssh_public_key_path = \"{}\"\navailability_zone = \"{}\"\ndefault_bundle_id = \"{}\"\nblueprint_id = \"{}\"\nkey_pair_name = \"\"\nuser_data = \"\"\n",
         cluster, region, ssh_public_key_path, availability_zone, default_bundle_id, blueprint_id
     )
 }
@@ -3031,7 +6919,7 @@ fn default_gce_config_contents(
     ssh_user: &str,
 ) -> String {
     format!(
-        "cluster_name = \"{}\"\n\n[gce]\nproject = \"{}\"\nzone = \"{}\"\nssh_public_key_path = \"{}\"\ndefault_machine_type = \"{}\"\nimage_family = \"{}\"\nimage_project = \"{}\"\nssh_user = \"{}\"\n",
+        "cluster_name = \"{}\"\n\n[gce]\nproject = \"{}\"\nzone = \"{}\"\nssh_public_key_path = \"{}\"\ndefault_machine_type = \"{}\"\nimage_family = \"{}\"\nimage_project = \"{}\"\nssh_user = \"{}\"\nuser_data = \"\"\n",
         cluster, project, zone, ssh_public_key_path, machine_type, image_family, image_project, ssh_user
     )
 }
@@ -3042,10 +6930,27 @@ fn default_droplet_config_contents(
     ssh_public_key_path: &str,
     default_size: &str,
     image: &str,
+    ssh_key_fingerprint: &str,
+) -> String {
+    format!(
+        "cluster_name = \"{}\"\n\n[droplet]\nregion = \"{}\"\nssh_public_key_path = \"{}\"\ndefault_size = \"{}\"\nimage = \"{}\"\nssh_key_fingerprint = \"{}\"\nuser_data = \"\"\n",
+        cluster, region, ssh_public_key_path, default_size, image, ssh_key_fingerprint
+    )
+}
+
+fn default_openstack_config_contents(
+    cluster: &str,
+    auth_url: &str,
+    project: &str,
+    region: &str,
+    flavor: &str,
+    image: &str,
+    network: &str,
+    ssh_public_key_path: &str,
 ) -> String {
     format!(
-        "cluster_name = \"{}\"\n\n[droplet]\nregion = \"{}\"\nssh_public_key_path = \"{}\"\ndefault_size = \"{}\"\nimage = \"{}\"\nssh_key_fingerprint = \"\"\n",
-        cluster, region, ssh_public_key_path, default_size, image
+        "cluster_name = \"{}\"\n\n[openstack]\nauth_url = \"{}\"\nproject = \"{}\"\nregion = \"{}\"\ndefault_flavor = \"{}\"\nimage = \"{}\"\nnetwork = \"{}\"\nssh_public_key_path = \"{}\"\nkeypair_name = \"\"\n",
+        cluster, auth_url, project, region, flavor, image, network, ssh_public_key_path
     )
 }
 
@@ -3054,35 +6959,118 @@ fn load_global_config(config_root: &Path) -> Result<GlobalConfig> {
     if !path.exists() {
         return Ok(GlobalConfig::default());
     }
-    let contents = fs::read_to_string(&path)
-        .with_context(|| format!("read config file {}", path.display()))?;
-    let mut config: GlobalConfig =
-        toml::from_str(&contents).with_context(|| format!("parse config {}", path.display()))?;
+    let mut config: GlobalConfig = Figment::new()
+        .merge(Toml::file(&path))
+        .extract()
+        .with_context(|| format!("parse config {}", path.display()))?;
     normalize_aws_section(&mut config.ec2);
     normalize_lightsail_section(&mut config.lightsail);
     normalize_gce_section(&mut config.gce);
     normalize_droplet_section(&mut config.droplet);
+    normalize_openstack_section(&mut config.openstack);
     Ok(config)
 }
 
-fn load_cluster_config(path: &Path, provider: &str) -> Result<ClusterConfig> {
-    if !path.exists() {
+/// Maps `VMCLI_<PROVIDER>_<FIELD>` env vars (e.g. `VMCLI_EC2_REGION`) onto
+/// the nested `<provider>.<field>` key figment expects. A plain
+/// `Env::prefixed("VMCLI_").split("_")` can't be used here because it would
+/// split every underscore, including the ones inside multi-word field names
+/// like `ssh_public_key_path` — so the provider segment is matched against
+/// the known provider names first, and only the remainder becomes the field
+/// name.
+fn vmcli_env_provider() -> Env {
+    const PROVIDER_ENV_PREFIXES: &[(&str, &str)] = &[
+        ("EC2_", EC2_PROVIDER),
+        ("LIGHTSAIL_", LIGHTSAIL_PROVIDER),
+        ("GCE_", GCE_PROVIDER),
+        ("DROPLET_", DROPLET_PROVIDER),
+        ("OPENSTACK_", OPENSTACK_PROVIDER),
+    ];
+    Env::prefixed("VMCLI_").map(|key| {
+        let upper = key.as_str().to_ascii_uppercase();
+        for (prefix, section) in PROVIDER_ENV_PREFIXES {
+            if let Some(field) = upper.strip_prefix(prefix) {
+                return format!("{}.{}", section, field.to_ascii_lowercase()).into();
+            }
+        }
+        key.as_str().to_ascii_lowercase().into()
+    })
+}
+
+/// Builds the layered config `Figment` for one cluster: the global
+/// `config.toml`, then the per-cluster `config.toml`, then `VMCLI_` env
+/// overrides, merged in that precedence order (later merges win). Either
+/// TOML file may be absent (a fresh `init` hasn't run `vmcli <provider>
+/// init` yet at the global layer, say); figment simply contributes nothing
+/// for a layer whose file doesn't exist.
+fn layered_config_figment(config_root: &Path, cluster_path: &Path) -> Figment {
+    let mut figment = Figment::new();
+    let global_path = global_config_path(config_root);
+    if global_path.exists() {
+        figment = figment.merge(Toml::file(&global_path));
+    }
+    if cluster_path.exists() {
+        figment = figment.merge(Toml::file(cluster_path));
+    }
+    figment.merge(vmcli_env_provider())
+}
+
+/// Loads one cluster's fully layered config (global + per-cluster + env, via
+/// `layered_config_figment`) and returns both the resolved `ClusterConfig`
+/// and the `Figment` that produced it, so callers printing `vmcli <provider>
+/// config` can ask the figment which layer supplied each field (see
+/// `config_field_source`).
+fn load_layered_cluster_config(
+    config_root: &Path,
+    provider: &str,
+    cluster: &str,
+    cluster_path: &Path,
+) -> Result<(ClusterConfig, Figment)> {
+    if !cluster_path.exists() {
+        let candidates = discover_configured_clusters(config_root, provider);
         bail!(
-            "config file {} not found; run 'vmcli {} init <cluster>'",
-            path.display(),
-            provider
+            "config file {} not found; run 'vmcli {} init <cluster>'{}",
+            cluster_path.display(),
+            provider,
+            did_you_mean(cluster, &candidates)
         );
     }
-    let contents =
-        fs::read_to_string(path).with_context(|| format!("read config file {}", path.display()))?;
-    let mut config: ClusterConfig =
-        toml::from_str(&contents).with_context(|| format!("parse config {}", path.display()))?;
+    let figment = layered_config_figment(config_root, cluster_path);
+    let mut config: ClusterConfig = figment
+        .extract()
+        .with_context(|| format!("load layered config for {}", cluster_path.display()))?;
     config.cluster_name = normalize_optional(config.cluster_name);
     normalize_aws_section(&mut config.ec2);
     normalize_lightsail_section(&mut config.lightsail);
     normalize_gce_section(&mut config.gce);
     normalize_droplet_section(&mut config.droplet);
-    Ok(config)
+    normalize_openstack_section(&mut config.openstack);
+    Ok((config, figment))
+}
+
+/// Labels the `Figment` layer that supplied `<section>.<field>`'s final
+/// merged value, for annotating `vmcli <provider> config` output. Mirrors
+/// the old hand-tracked precedence (env > cluster > global > default), but
+/// reads it directly off the provider stack's own merge metadata instead of
+/// three booleans threaded through every call site.
+fn config_field_source(
+    figment: &Figment,
+    cluster_path: &Path,
+    section: &str,
+    field: &str,
+) -> &'static str {
+    let key = format!("{}.{}", section, field);
+    let Some(metadata) = figment.find_metadata(&key) else {
+        return "default";
+    };
+    if metadata.name.to_ascii_lowercase().contains("environment") {
+        return "env";
+    }
+    match &metadata.source {
+        Some(Source::File(path)) if path == cluster_path => "cluster",
+        Some(Source::File(_)) => "global",
+        _ => "default",
+    }
 }
 
 fn normalize_optional(value: Option<String>) -> Option<String> {
@@ -3096,6 +7084,17 @@ fn normalize_optional(value: Option<String>) -> Option<String> {
     })
 }
 
+fn print_config_field(name: &str, value: &str, source: &str) {
+    println!("{}={} (source={})", name, value, source);
+}
+
+fn print_optional_config_field(name: &str, value: Option<&str>, source: &str) {
+    match value {
+        Some(value) => println!("{}={} (source={})", name, value, source),
+        None => println!("{}=<unset> (source=default)", name),
+    }
+}
+
 fn normalize_aws_section(section: &mut Option<AwsConfigSection>) {
     let Some(ec2) = section.as_mut() else {
         return;
@@ -3104,6 +7103,8 @@ fn normalize_aws_section(section: &mut Option<AwsConfigSection>) {
     ec2.ssh_public_key_path = normalize_optional(ec2.ssh_public_key_path.take());
     ec2.default_instance_type = normalize_optional(ec2.default_instance_type.take());
     ec2.ami_id = normalize_optional(ec2.ami_id.take());
+    ec2.user_data = normalize_optional(ec2.user_data.take());
+    ec2.vpc_cidr = normalize_optional(ec2.vpc_cidr.take());
 }
 
 fn normalize_lightsail_section(section: &mut Option<LightsailConfigSection>) {
@@ -3116,6 +7117,7 @@ fn normalize_lightsail_section(section: &mut Option<LightsailConfigSection>) {
     lightsail.default_bundle_id = normalize_optional(lightsail.default_bundle_id.take());
     lightsail.blueprint_id = normalize_optional(lightsail.blueprint_id.take());
     lightsail.key_pair_name = normalize_optional(lightsail.key_pair_name.take());
+    lightsail.user_data = normalize_optional(lightsail.user_data.take());
 }
 
 fn normalize_gce_section(section: &mut Option<GceConfigSection>) {
@@ -3129,6 +7131,7 @@ fn normalize_gce_section(section: &mut Option<GceConfigSection>) {
     gce.image_family = normalize_optional(gce.image_family.take());
     gce.image_project = normalize_optional(gce.image_project.take());
     gce.ssh_user = normalize_optional(gce.ssh_user.take());
+    gce.user_data = normalize_optional(gce.user_data.take());
 }
 
 fn normalize_droplet_section(section: &mut Option<DropletConfigSection>) {
@@ -3140,122 +7143,36 @@ fn normalize_droplet_section(section: &mut Option<DropletConfigSection>) {
     droplet.default_size = normalize_optional(droplet.default_size.take());
     droplet.image = normalize_optional(droplet.image.take());
     droplet.ssh_key_fingerprint = normalize_optional(droplet.ssh_key_fingerprint.take());
+    droplet.user_data = normalize_optional(droplet.user_data.take());
 }
 
-fn merge_aws_section(
-    base: Option<AwsConfigSection>,
-    overlay: Option<AwsConfigSection>,
-) -> AwsConfigSection {
-    let mut merged = base.unwrap_or_default();
-    let overlay = overlay.unwrap_or_default();
-    if overlay.region.is_some() {
-        merged.region = overlay.region;
-    }
-    if overlay.ssh_public_key_path.is_some() {
-        merged.ssh_public_key_path = overlay.ssh_public_key_path;
-    }
-    if overlay.default_instance_type.is_some() {
-        merged.default_instance_type = overlay.default_instance_type;
-    }
-    if overlay.ami_id.is_some() {
-        merged.ami_id = overlay.ami_id;
-    }
-    merged
-}
-
-fn merge_lightsail_section(
-    base: Option<LightsailConfigSection>,
-    overlay: Option<LightsailConfigSection>,
-) -> LightsailConfigSection {
-    let mut merged = base.unwrap_or_default();
-    let overlay = overlay.unwrap_or_default();
-    if overlay.region.is_some() {
-        merged.region = overlay.region;
-    }
-    if overlay.ssh_public_key_path.is_some() {
-        merged.ssh_public_key_path = overlay.ssh_public_key_path;
-    }
-    if overlay.availability_zone.is_some() {
-        merged.availability_zone = overlay.availability_zone;
-    }
-    if overlay.default_bundle_id.is_some() {
-        merged.default_bundle_id = overlay.default_bundle_id;
-    }
-    if overlay.blueprint_id.is_some() {
-        merged.blueprint_id = overlay.blueprint_id;
-    }
-    if overlay.key_pair_name.is_some() {
-        merged.key_pair_name = overlay.key_pair_name;
-    }
-    merged
-}
-
-fn merge_gce_section(
-    base: Option<GceConfigSection>,
-    overlay: Option<GceConfigSection>,
-) -> GceConfigSection {
-    let mut merged = base.unwrap_or_default();
-    let overlay = overlay.unwrap_or_default();
-    if overlay.project.is_some() {
-        merged.project = overlay.project;
-    }
-    if overlay.zone.is_some() {
-        merged.zone = overlay.zone;
-    }
-    if overlay.ssh_public_key_path.is_some() {
-        merged.ssh_public_key_path = overlay.ssh_public_key_path;
-    }
-    if overlay.default_machine_type.is_some() {
-        merged.default_machine_type = overlay.default_machine_type;
-    }
-    if overlay.image_family.is_some() {
-        merged.image_family = overlay.image_family;
-    }
-    if overlay.image_project.is_some() {
-        merged.image_project = overlay.image_project;
-    }
-    if overlay.ssh_user.is_some() {
-        merged.ssh_user = overlay.ssh_user;
-    }
-    merged
-}
-
-fn merge_droplet_section(
-    base: Option<DropletConfigSection>,
-    overlay: Option<DropletConfigSection>,
-) -> DropletConfigSection {
-    let mut merged = base.unwrap_or_default();
-    let overlay = overlay.unwrap_or_default();
-    if overlay.region.is_some() {
-        merged.region = overlay.region;
-    }
-    if overlay.ssh_public_key_path.is_some() {
-        merged.ssh_public_key_path = overlay.ssh_public_key_path;
-    }
-    if overlay.default_size.is_some() {
-        merged.default_size = overlay.default_size;
-    }
-    if overlay.image.is_some() {
-        merged.image = overlay.image;
-    }
-    if overlay.ssh_key_fingerprint.is_some() {
-        merged.ssh_key_fingerprint = overlay.ssh_key_fingerprint;
-    }
-    merged
+fn normalize_openstack_section(section: &mut Option<OpenstackConfigSection>) {
+    let Some(openstack) = section.as_mut() else {
+        return;
+    };
+    openstack.auth_url = normalize_optional(openstack.auth_url.take());
+    openstack.project = normalize_optional(openstack.project.take());
+    openstack.region = normalize_optional(openstack.region.take());
+    openstack.default_flavor = normalize_optional(openstack.default_flavor.take());
+    openstack.image = normalize_optional(openstack.image.take());
+    openstack.network = normalize_optional(openstack.network.take());
+    openstack.ssh_public_key_path = normalize_optional(openstack.ssh_public_key_path.take());
+    openstack.keypair_name = normalize_optional(openstack.keypair_name.take());
 }
 
 fn load_aws_config(
     config_root: &Path,
     cluster: &str,
     override_path: Option<&str>,
+    profile: Option<&str>,
 ) -> Result<AwsEffectiveConfig> {
-    let global_config = load_global_config(config_root)?;
     let cluster_path = match override_path {
         Some(path) => PathBuf::from(path),
         None => ec2_cluster_config_path(config_root, cluster)?,
     };
-    let cluster_config = load_cluster_config(&cluster_path, EC2_PROVIDER)?;
-    if let Some(name) = cluster_config.cluster_name.as_ref() {
+    let (layered, _figment) =
+        load_layered_cluster_config(config_root, EC2_PROVIDER, cluster, &cluster_path)?;
+    if let Some(name) = layered.cluster_name.as_ref() {
         if name != cluster {
             bail!(
                 "cluster_name '{}' does not match requested cluster '{}' in {}",
@@ -3266,10 +7183,14 @@ fn load_aws_config(
         }
     }
 
-    let merged = merge_aws_section(global_config.ec2, cluster_config.ec2);
-    let region = merged
-        .region
-        .ok_or_else(|| anyhow!("ec2.region must be set in config"))?;
+    let merged = layered.ec2.unwrap_or_default();
+    let region = match merged.region.or_else(|| aws_config_file_region(profile)) {
+        Some(region) => region,
+        None => bail!(
+            "ec2.region must be set in config, via VMCLI_EC2_REGION, or as a `region` \
+             line under the matching profile in ~/.aws/config"
+        ),
+    };
     let ssh_public_key_path = merged
         .ssh_public_key_path
         .ok_or_else(|| anyhow!("ec2.ssh_public_key_path must be set in config"))?;
@@ -3278,6 +7199,13 @@ fn load_aws_config(
         .unwrap_or_else(|| DEFAULT_INSTANCE_TYPE.to_string());
 
     let ssh_config_path = ec2_cluster_ssh_config_path(config_root, cluster)?;
+    let firewall = if merged.firewall.is_empty() {
+        default_ec2_firewall_rules()
+    } else {
+        resolve_firewall_rules(merged.firewall)?
+    };
+    let vpc_cidr = merged.vpc_cidr.unwrap_or_else(|| DEFAULT_VPC_CIDR.to_string());
+    let subnets = resolve_subnet_specs(&region, merged.subnet_cidrs, merged.availability_zones)?;
 
     Ok(AwsEffectiveConfig {
         cluster_name: cluster.to_string(),
@@ -3285,22 +7213,67 @@ fn load_aws_config(
         ssh_public_key_path,
         default_instance_type,
         ami_id: merged.ami_id,
+        user_data: merged.user_data,
+        firewall,
         ssh_config_path,
+        vpc_cidr,
+        subnets,
     })
 }
 
+/// Resolves the `(availability_zone, cidr)` pairs for a cluster's subnets.
+/// When neither is configured, defaults to a single subnet in `<region>a`
+/// with `10.0.1.0/24`, matching the historical single-AZ behavior. When only
+/// one of the two lists is configured, the other is generated to match its
+/// length (sequential AZ letters, or sequential `10.0.N.0/24` CIDRs).
+fn resolve_subnet_specs(
+    region: &str,
+    mut cidrs: Vec<String>,
+    mut azs: Vec<String>,
+) -> Result<Vec<SubnetSpec>> {
+    if !cidrs.is_empty() && !azs.is_empty() && cidrs.len() != azs.len() {
+        bail!(
+            "ec2.subnet_cidrs and ec2.availability_zones must have the same length \
+             (got {} subnet_cidrs, {} availability_zones)",
+            cidrs.len(),
+            azs.len()
+        );
+    }
+
+    let count = cidrs.len().max(azs.len()).max(1);
+    if azs.is_empty() {
+        azs = (0..count)
+            .map(|index| format!("{}{}", region, (b'a' + index as u8) as char))
+            .collect();
+    }
+    if cidrs.is_empty() {
+        cidrs = (0..count)
+            .map(|index| format!("10.0.{}.0/24", index + 1))
+            .collect();
+    }
+
+    Ok(azs
+        .into_iter()
+        .zip(cidrs)
+        .map(|(availability_zone, cidr)| SubnetSpec {
+            availability_zone,
+            cidr,
+        })
+        .collect())
+}
+
 fn load_lightsail_config(
     config_root: &Path,
     cluster: &str,
     override_path: Option<&str>,
 ) -> Result<LightsailEffectiveConfig> {
-    let global_config = load_global_config(config_root)?;
     let cluster_path = match override_path {
         Some(path) => PathBuf::from(path),
         None => lightsail_cluster_config_path(config_root, cluster)?,
     };
-    let cluster_config = load_cluster_config(&cluster_path, LIGHTSAIL_PROVIDER)?;
-    if let Some(name) = cluster_config.cluster_name.as_ref() {
+    let (layered, _figment) =
+        load_layered_cluster_config(config_root, LIGHTSAIL_PROVIDER, cluster, &cluster_path)?;
+    if let Some(name) = layered.cluster_name.as_ref() {
         if name != cluster {
             bail!(
                 "cluster_name '{}' does not match requested cluster '{}' in {}",
@@ -3311,7 +7284,7 @@ fn load_lightsail_config(
         }
     }
 
-    let merged = merge_lightsail_section(global_config.lightsail, cluster_config.lightsail);
+    let merged = layered.lightsail.unwrap_or_default();
     let region = merged
         .region
         .unwrap_or_else(|| "ap-northeast-1".to_string());
@@ -3328,6 +7301,11 @@ fn load_lightsail_config(
         .blueprint_id
         .unwrap_or_else(|| DEFAULT_LIGHTSAIL_BLUEPRINT_ID.to_string());
     let ssh_config_path = lightsail_cluster_ssh_config_path(config_root, cluster)?;
+    let firewall = if merged.firewall.is_empty() {
+        default_lightsail_firewall_rules()
+    } else {
+        resolve_firewall_rules(merged.firewall)?
+    };
 
     Ok(LightsailEffectiveConfig {
         cluster_name: cluster.to_string(),
@@ -3337,6 +7315,8 @@ fn load_lightsail_config(
         default_bundle_id,
         blueprint_id,
         key_pair_name: merged.key_pair_name,
+        user_data: merged.user_data,
+        firewall,
         ssh_config_path,
     })
 }
@@ -3346,13 +7326,13 @@ fn load_gce_config(
     cluster: &str,
     override_path: Option<&str>,
 ) -> Result<GceEffectiveConfig> {
-    let global_config = load_global_config(config_root)?;
     let cluster_path = match override_path {
         Some(path) => PathBuf::from(path),
         None => gce_cluster_config_path(config_root, cluster)?,
     };
-    let cluster_config = load_cluster_config(&cluster_path, GCE_PROVIDER)?;
-    if let Some(name) = cluster_config.cluster_name.as_ref() {
+    let (layered, _figment) =
+        load_layered_cluster_config(config_root, GCE_PROVIDER, cluster, &cluster_path)?;
+    if let Some(name) = layered.cluster_name.as_ref() {
         if name != cluster {
             bail!(
                 "cluster_name '{}' does not match requested cluster '{}' in {}",
@@ -3363,7 +7343,7 @@ fn load_gce_config(
         }
     }
 
-    let merged = merge_gce_section(global_config.gce, cluster_config.gce);
+    let merged = layered.gce.unwrap_or_default();
     let project = match merged.project {
         Some(value) => value,
         None => env::var("GOOGLE_CLOUD_PROJECT")
@@ -3399,6 +7379,7 @@ fn load_gce_config(
         image_family,
         image_project,
         ssh_user,
+        user_data: merged.user_data,
         ssh_config_path,
     })
 }
@@ -3408,13 +7389,13 @@ fn load_droplet_config(
     cluster: &str,
     override_path: Option<&str>,
 ) -> Result<DropletEffectiveConfig> {
-    let global_config = load_global_config(config_root)?;
     let cluster_path = match override_path {
         Some(path) => PathBuf::from(path),
         None => droplet_cluster_config_path(config_root, cluster)?,
     };
-    let cluster_config = load_cluster_config(&cluster_path, DROPLET_PROVIDER)?;
-    if let Some(name) = cluster_config.cluster_name.as_ref() {
+    let (layered, _figment) =
+        load_layered_cluster_config(config_root, DROPLET_PROVIDER, cluster, &cluster_path)?;
+    if let Some(name) = layered.cluster_name.as_ref() {
         if name != cluster {
             bail!(
                 "cluster_name '{}' does not match requested cluster '{}' in {}",
@@ -3425,7 +7406,7 @@ fn load_droplet_config(
         }
     }
 
-    let merged = merge_droplet_section(global_config.droplet, cluster_config.droplet);
+    let merged = layered.droplet.unwrap_or_default();
     let region = merged.region.unwrap_or_else(|| "sfo3".to_string());
     let ssh_public_key_path = merged
         .ssh_public_key_path
@@ -3445,6 +7426,66 @@ fn load_droplet_config(
         default_size,
         image,
         ssh_key_fingerprint: merged.ssh_key_fingerprint,
+        user_data: merged.user_data,
+        ssh_config_path,
+    })
+}
+
+fn load_openstack_config(
+    config_root: &Path,
+    cluster: &str,
+    override_path: Option<&str>,
+) -> Result<OpenstackEffectiveConfig> {
+    let cluster_path = match override_path {
+        Some(path) => PathBuf::from(path),
+        None => openstack_cluster_config_path(config_root, cluster)?,
+    };
+    let (layered, _figment) =
+        load_layered_cluster_config(config_root, OPENSTACK_PROVIDER, cluster, &cluster_path)?;
+    if let Some(name) = layered.cluster_name.as_ref() {
+        if name != cluster {
+            bail!(
+                "cluster_name '{}' does not match requested cluster '{}' in {}",
+                name,
+                cluster,
+                cluster_path.display()
+            );
+        }
+    }
+
+    let merged = layered.openstack.unwrap_or_default();
+    let auth_url = merged
+        .auth_url
+        .ok_or_else(|| anyhow!("openstack.auth_url must be set in config"))?;
+    let project = merged
+        .project
+        .ok_or_else(|| anyhow!("openstack.project must be set in config"))?;
+    let region = merged.region.unwrap_or_else(|| "RegionOne".to_string());
+    let default_flavor = merged
+        .default_flavor
+        .unwrap_or_else(|| "m1.small".to_string());
+    let image = merged
+        .image
+        .ok_or_else(|| anyhow!("openstack.image must be set in config"))?;
+    let network = merged
+        .network
+        .ok_or_else(|| anyhow!("openstack.network must be set in config"))?;
+    let ssh_public_key_path = merged
+        .ssh_public_key_path
+        .unwrap_or_else(|| default_ssh_public_key_path(config_root));
+    let keypair_name = merged.keypair_name.unwrap_or_default();
+    let ssh_config_path = openstack_cluster_ssh_config_path(config_root, cluster)?;
+
+    Ok(OpenstackEffectiveConfig {
+        cluster_name: cluster.to_string(),
+        auth_url,
+        project,
+        region,
+        default_flavor,
+        image,
+        network,
+        ssh_public_key_path,
+        keypair_name,
         ssh_config_path,
     })
 }
@@ -3469,6 +7510,53 @@ fn derive_private_key_path(public_key_path: &str) -> String {
     trimmed.strip_suffix(".pub").unwrap_or(trimmed).to_string()
 }
 
+/// Default `--concurrency` for worker-pool commands: one worker per available CPU.
+fn default_concurrency() -> usize {
+    std::thread::available_parallelism()
+        .map(|value| value.get())
+        .unwrap_or(1)
+}
+
+/// Runs `work` over `items` using a bounded pool of at most `concurrency` worker
+/// threads, returning results in the same order as `items` (not completion
+/// order). Used to fan out per-instance/per-cluster CLI and SDK calls so a
+/// single slow round-trip doesn't block the rest.
+fn run_with_concurrency<T, R, F>(items: Vec<T>, concurrency: usize, work: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Send + Sync,
+{
+    let concurrency = concurrency.max(1);
+    let total = items.len();
+    if total == 0 {
+        return Vec::new();
+    }
+
+    let queue = Mutex::new(items.into_iter().enumerate().collect::<VecDeque<_>>());
+    let results = Mutex::new(Vec::with_capacity(total));
+    let work = &work;
+    let queue = &queue;
+    let results = &results;
+
+    std::thread::scope(|scope| {
+        for _ in 0..concurrency.min(total) {
+            scope.spawn(move || loop {
+                let next = queue.lock().unwrap().pop_front();
+                let Some((index, item)) = next else {
+                    break;
+                };
+                let result = work(item);
+                results.lock().unwrap().push((index, result));
+            });
+        }
+    });
+
+    let mut results = results.lock().unwrap().drain(..).collect::<Vec<_>>();
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
 fn check_aws_cli() -> Result<()> {
     match Command::new("aws").arg("--version").output() {
         Ok(output) => {
@@ -3532,13 +7620,82 @@ fn check_doctl_cli() -> Result<()> {
     }
 }
 
-fn ensure_no_profile_env() -> Result<()> {
+fn check_openstack_cli() -> Result<()> {
+    match Command::new("openstack").arg("--version").output() {
+        Ok(output) => {
+            if output.status.success() {
+                Ok(())
+            } else {
+                let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+                if stderr.is_empty() {
+                    bail!("openstack CLI failed to run")
+                } else {
+                    bail!("openstack CLI failed to run: {}", stderr)
+                }
+            }
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            bail!("openstack CLI not found in PATH")
+        }
+        Err(err) => Err(err).context("failed to execute openstack CLI"),
+    }
+}
+
+fn ensure_no_profile_env(profile: Option<&str>) -> Result<()> {
+    if profile.is_some() {
+        return Ok(());
+    }
     if env::var_os("AWS_PROFILE").is_some() || env::var_os("AWS_DEFAULT_PROFILE").is_some() {
-        bail!("AWS profile is not supported; use AWS_ACCESS_KEY_ID/AWS_SECRET_ACCESS_KEY");
+        bail!(
+            "AWS profile is not supported via environment; pass --profile <name> explicitly, \
+             or use AWS_ACCESS_KEY_ID/AWS_SECRET_ACCESS_KEY"
+        );
     }
     Ok(())
 }
 
+/// Path to the shared AWS config file, honoring `$AWS_CONFIG_FILE` and
+/// falling back to the standard `~/.aws/config` location.
+fn aws_config_file_path() -> Result<PathBuf> {
+    if let Ok(path) = env::var("AWS_CONFIG_FILE") {
+        return Ok(PathBuf::from(path));
+    }
+    Ok(home_dir()?.join(".aws").join("config"))
+}
+
+/// Best-effort lookup of the default `region` for a named profile (or
+/// `[default]` when `profile` is `None`) from `~/.aws/config`. Returns
+/// `None` whenever the file, section, or key is missing so callers can
+/// fall back to an explicit error instead.
+fn aws_config_file_region(profile: Option<&str>) -> Option<String> {
+    let path = aws_config_file_path().ok()?;
+    let contents = fs::read_to_string(path).ok()?;
+    let target_header = match profile {
+        Some(name) => format!("profile {}", name),
+        None => "default".to_string(),
+    };
+    let mut in_target_section = false;
+    for line in contents.lines() {
+        let trimmed = line.trim();
+        if let Some(header) = trimmed.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            in_target_section = header.trim() == target_header;
+            continue;
+        }
+        if !in_target_section {
+            continue;
+        }
+        if let Some((key, value)) = trimmed.split_once('=') {
+            if key.trim() == "region" {
+                let region = value.trim();
+                if !region.is_empty() {
+                    return Some(region.to_string());
+                }
+            }
+        }
+    }
+    None
+}
+
 fn print_banner(aws: &AwsCli) -> Result<()> {
     let access_key_id = match env::var("AWS_ACCESS_KEY_ID") {
         Ok(value) if !value.trim().is_empty() => value,
@@ -3546,8 +7703,12 @@ fn print_banner(aws: &AwsCli) -> Result<()> {
     };
     let identity = aws.get_caller_identity()?;
     println!(
-        "profile=env region={} access_key_id={} account={} arn={}",
-        aws.region, access_key_id, identity.account, identity.arn
+        "profile={} region={} access_key_id={} account={} arn={}",
+        aws.profile.as_deref().unwrap_or("env"),
+        aws.region,
+        access_key_id,
+        identity.account,
+        identity.arn
     );
     Ok(())
 }
@@ -3556,6 +7717,33 @@ fn resource_name(cluster: &str, suffix: &str) -> String {
     format!("{}-{}", cluster, suffix)
 }
 
+/// Parses a short duration spec like "30m", "2h", or "1d" into seconds.
+/// A bare number of seconds (e.g. "90") is also accepted.
+fn parse_lifetime_secs(spec: &str) -> Result<u64> {
+    let spec = spec.trim();
+    if spec.is_empty() {
+        bail!("--lifetime must not be empty");
+    }
+    let (digits, unit_secs) = match spec.chars().last() {
+        Some('s') => (&spec[..spec.len() - 1], 1),
+        Some('m') => (&spec[..spec.len() - 1], 60),
+        Some('h') => (&spec[..spec.len() - 1], 3600),
+        Some('d') => (&spec[..spec.len() - 1], 86400),
+        _ => (spec, 1),
+    };
+    let amount: u64 = digits
+        .parse()
+        .with_context(|| format!("invalid --lifetime '{}'", spec))?;
+    Ok(amount * unit_secs)
+}
+
+fn unix_timestamp_now() -> Result<u64> {
+    Ok(std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .context("system clock is before the unix epoch")?
+        .as_secs())
+}
+
 fn tag_spec(resource_type: &str, name: &str, cluster: &str) -> String {
     format!(
         "ResourceType={},Tags=[{{Key=Name,Value={}}},{{Key=Cluster,Value={}}}]",
@@ -3594,20 +7782,36 @@ fn find_vpc(aws: &AwsCli, cluster: &str) -> Result<Option<String>> {
     }
 }
 
-fn find_subnet(aws: &AwsCli, cluster: &str) -> Result<Option<String>> {
-    let name = resource_name(cluster, "subnet");
+/// Looks up a single AZ subnet by its exact tagged `name` (e.g.
+/// `mycluster-subnet-a`), not just by cluster — so multiple per-AZ subnets in
+/// the same cluster never collide in a single lookup.
+fn find_subnet(aws: &AwsCli, cluster: &str, name: &str) -> Result<Option<String>> {
     let mut args = aws_args(&["ec2", "describe-subnets", "--output", "json"]);
-    append_filters(&mut args, &tag_filters(&name, cluster));
+    append_filters(&mut args, &tag_filters(name, cluster));
     let output = aws.run(&args)?;
     let result: DescribeSubnets =
         serde_json::from_str(&output).context("parse describe-subnets")?;
     match result.subnets.len() {
         0 => Ok(None),
         1 => Ok(Some(result.subnets[0].subnet_id.clone())),
-        _ => bail!("multiple subnets found for cluster {}", cluster),
+        _ => bail!("multiple subnets found for cluster {} with name {}", cluster, name),
     }
 }
 
+/// Looks up every subnet tagged for `cluster`, regardless of AZ/name; used by
+/// `prune` to tear down all of a cluster's subnets.
+fn find_subnets_by_cluster(aws: &AwsCli, cluster: &str) -> Result<Vec<String>> {
+    let mut args = aws_args(&["ec2", "describe-subnets", "--output", "json"]);
+    append_filters(
+        &mut args,
+        &[format!("Name=tag:Cluster,Values={}", cluster)],
+    );
+    let output = aws.run(&args)?;
+    let result: DescribeSubnets =
+        serde_json::from_str(&output).context("parse describe-subnets")?;
+    Ok(result.subnets.into_iter().map(|s| s.subnet_id).collect())
+}
+
 fn find_internet_gateway(aws: &AwsCli, cluster: &str) -> Result<Option<InternetGateway>> {
     let name = resource_name(cluster, "igw");
     let mut args = aws_args(&["ec2", "describe-internet-gateways", "--output", "json"]);
@@ -3652,18 +7856,83 @@ fn find_security_group(aws: &AwsCli, cluster: &str) -> Result<Option<String>> {
     }
 }
 
+fn find_placement_group(aws: &AwsCli, cluster: &str) -> Result<Option<String>> {
+    let name = resource_name(cluster, "pg");
+    let mut args = aws_args(&["ec2", "describe-placement-groups", "--output", "json"]);
+    args.extend(aws_args(&["--filters", &format!("Name=group-name,Values={}", name)]));
+    let output = aws.run(&args)?;
+    let result: DescribePlacementGroups =
+        serde_json::from_str(&output).context("parse describe-placement-groups")?;
+    match result.placement_groups.len() {
+        0 => Ok(None),
+        1 => Ok(Some(result.placement_groups[0].group_name.clone())),
+        _ => bail!("multiple placement groups found for cluster {}", cluster),
+    }
+}
+
+/// Creates (idempotently) the cluster's EC2 placement group for the given
+/// strategy, returning its group name for use as `launch_instance`'s
+/// `--placement GroupName=...`. `partition_count` only applies to the
+/// `partition` strategy.
+fn ensure_placement_group(
+    aws: &AwsCli,
+    config: &AwsEffectiveConfig,
+    strategy: PlacementGroupStrategy,
+    partition_count: Option<u32>,
+) -> Result<String> {
+    let name = resource_name(&config.cluster_name, "pg");
+    if let Some(existing) = find_placement_group(aws, &config.cluster_name)? {
+        aws.plan_mutation(PlanChange::NoChange, "ec2 placement-group", format!("name={}", existing));
+        return Ok(existing);
+    }
+
+    if aws.plan_mutation(
+        PlanChange::Create,
+        "ec2 placement-group",
+        format!("name={}, strategy={}", name, strategy.as_str()),
+    ) {
+        return Ok(format!("<planned:{}>", name));
+    }
+
+    let tag_spec = tag_spec("placement-group", &name, &config.cluster_name);
+    let mut args = aws_args(&[
+        "ec2",
+        "create-placement-group",
+        "--group-name",
+        &name,
+        "--strategy",
+        strategy.as_str(),
+    ]);
+    if let (PlacementGroupStrategy::Partition, Some(partition_count)) = (strategy, partition_count) {
+        args.extend(aws_args(&["--partition-count", &partition_count.to_string()]));
+    }
+    args.extend(aws_args(&["--tag-specifications"]));
+    args.push(tag_spec);
+    aws.run(&args)?;
+    Ok(name)
+}
+
 fn ensure_vpc(aws: &AwsCli, config: &AwsEffectiveConfig) -> Result<String> {
+    let vpc_name = resource_name(&config.cluster_name, "vpc");
     if let Some(vpc_id) = find_vpc(aws, &config.cluster_name)? {
+        aws.plan_mutation(PlanChange::NoChange, "ec2 vpc", format!("name={} id={}", vpc_name, vpc_id));
         return Ok(vpc_id);
     }
 
-    let vpc_name = resource_name(&config.cluster_name, "vpc");
+    if aws.plan_mutation(
+        PlanChange::Create,
+        "ec2 vpc",
+        format!("name={}, cidr={}", vpc_name, config.vpc_cidr),
+    ) {
+        return Ok(format!("<planned:{}>", vpc_name));
+    }
+
     let tag_spec = tag_spec("vpc", &vpc_name, &config.cluster_name);
     let mut args = aws_args(&[
         "ec2",
         "create-vpc",
         "--cidr-block",
-        "10.0.0.0/16",
+        &config.vpc_cidr,
         "--tag-specifications",
     ]);
     args.push(tag_spec);
@@ -3672,40 +7941,67 @@ fn ensure_vpc(aws: &AwsCli, config: &AwsEffectiveConfig) -> Result<String> {
     Ok(vpc_id)
 }
 
-fn ensure_subnet(aws: &AwsCli, config: &AwsEffectiveConfig, vpc_id: &str) -> Result<String> {
-    let subnet_id = if let Some(existing) = find_subnet(aws, &config.cluster_name)? {
-        existing
-    } else {
-        let subnet_name = resource_name(&config.cluster_name, "subnet");
-        let tag_spec = tag_spec("subnet", &subnet_name, &config.cluster_name);
-        let mut args = aws_args(&[
-            "ec2",
-            "create-subnet",
-            "--vpc-id",
-            vpc_id,
-            "--cidr-block",
-            "10.0.1.0/24",
-            "--tag-specifications",
-        ]);
-        args.push(tag_spec);
-        args.extend(aws_args(&[
-            "--query",
-            "Subnet.SubnetId",
-            "--output",
-            "text",
-        ]));
-        aws.run(&args)?
-    };
+/// Ensures one tagged subnet per configured availability zone exists (names
+/// like `<cluster>-subnet-a`, `<cluster>-subnet-b`), returning their ids in
+/// `config.subnets` order. The first entry is the cluster's primary subnet,
+/// used for single-instance placement until multi-node placement lands.
+fn ensure_subnet(aws: &AwsCli, config: &AwsEffectiveConfig, vpc_id: &str) -> Result<Vec<String>> {
+    let mut subnet_ids = Vec::with_capacity(config.subnets.len());
+    for spec in &config.subnets {
+        let subnet_name = resource_name(&config.cluster_name, &format!("subnet-{}", spec.suffix()));
+        let subnet_id = if let Some(existing) = find_subnet(aws, &config.cluster_name, &subnet_name)? {
+            aws.plan_mutation(
+                PlanChange::NoChange,
+                "ec2 subnet",
+                format!("name={} id={}", subnet_name, existing),
+            );
+            existing
+        } else if aws.plan_mutation(
+            PlanChange::Create,
+            "ec2 subnet",
+            format!(
+                "name={}, vpc={}, az={}, cidr={}",
+                subnet_name, vpc_id, spec.availability_zone, spec.cidr
+            ),
+        ) {
+            format!("<planned:{}>", subnet_name)
+        } else {
+            let tag_spec = tag_spec("subnet", &subnet_name, &config.cluster_name);
+            let mut args = aws_args(&[
+                "ec2",
+                "create-subnet",
+                "--vpc-id",
+                vpc_id,
+                "--cidr-block",
+                &spec.cidr,
+                "--availability-zone",
+                &spec.availability_zone,
+                "--tag-specifications",
+            ]);
+            args.push(tag_spec);
+            args.extend(aws_args(&[
+                "--query",
+                "Subnet.SubnetId",
+                "--output",
+                "text",
+            ]));
+            aws.run(&args)?
+        };
 
-    let args = aws_args(&[
-        "ec2",
-        "modify-subnet-attribute",
-        "--subnet-id",
-        &subnet_id,
-        "--map-public-ip-on-launch",
-    ]);
-    let _ = aws.run(&args)?;
-    Ok(subnet_id)
+        if !aws.dry_run {
+            let args = aws_args(&[
+                "ec2",
+                "modify-subnet-attribute",
+                "--subnet-id",
+                &subnet_id,
+                "--map-public-ip-on-launch",
+            ]);
+            let _ = aws.run(&args)?;
+        }
+
+        subnet_ids.push(subnet_id);
+    }
+    Ok(subnet_ids)
 }
 
 fn ensure_internet_gateway(
@@ -3714,10 +8010,17 @@ fn ensure_internet_gateway(
     vpc_id: &str,
 ) -> Result<String> {
     let mut igw = find_internet_gateway(aws, &config.cluster_name)?;
+    let igw_name = resource_name(&config.cluster_name, "igw");
     let igw_id = if let Some(existing) = igw.as_ref() {
+        aws.plan_mutation(
+            PlanChange::NoChange,
+            "ec2 internet-gateway",
+            format!("name={} id={}", igw_name, existing.internet_gateway_id),
+        );
         existing.internet_gateway_id.clone()
+    } else if aws.plan_mutation(PlanChange::Create, "ec2 internet-gateway", format!("name={}", igw_name)) {
+        format!("<planned:{}>", igw_name)
     } else {
-        let igw_name = resource_name(&config.cluster_name, "igw");
         let tag_spec = tag_spec("internet-gateway", &igw_name, &config.cluster_name);
         let mut args = aws_args(&["ec2", "create-internet-gateway", "--tag-specifications"]);
         args.push(tag_spec);
@@ -3750,6 +8053,13 @@ fn ensure_internet_gateway(
         .unwrap_or(false);
 
     if !attached {
+        if aws.plan_mutation(
+            PlanChange::Modify,
+            "ec2 internet-gateway",
+            format!("attach {} to vpc {}", igw_id, vpc_id),
+        ) {
+            return Ok(igw_id);
+        }
         let args = aws_args(&[
             "ec2",
             "attach-internet-gateway",
@@ -3768,14 +8078,21 @@ fn ensure_route_table(
     aws: &AwsCli,
     config: &AwsEffectiveConfig,
     vpc_id: &str,
-    subnet_id: &str,
+    subnet_ids: &[String],
     igw_id: &str,
 ) -> Result<String> {
     let route_table = find_route_table(aws, &config.cluster_name)?;
+    let rt_name = resource_name(&config.cluster_name, "rt");
     let route_table_id = if let Some(existing) = route_table.as_ref() {
+        aws.plan_mutation(
+            PlanChange::NoChange,
+            "ec2 route-table",
+            format!("name={} id={}", rt_name, existing.route_table_id),
+        );
         existing.route_table_id.clone()
+    } else if aws.plan_mutation(PlanChange::Create, "ec2 route-table", format!("name={}, vpc={}", rt_name, vpc_id)) {
+        format!("<planned:{}>", rt_name)
     } else {
-        let rt_name = resource_name(&config.cluster_name, "rt");
         let tag_spec = tag_spec("route-table", &rt_name, &config.cluster_name);
         let mut args = aws_args(&[
             "ec2",
@@ -3791,15 +8108,41 @@ fn ensure_route_table(
             "--output",
             "text",
         ]));
-        aws.run(&args)?
+        let new_route_table_id = aws.run(&args)?;
+        wait_until(
+            &format!("new route table {} to be visible", new_route_table_id),
+            RESOURCE_VISIBILITY_TIMEOUT,
+            || Ok(find_route_table(aws, &config.cluster_name)?.is_some()),
+        )?;
+        new_route_table_id
     };
 
     ensure_default_route(aws, &route_table_id, igw_id)?;
-    ensure_route_table_association(aws, &route_table_id, subnet_id)?;
+    for subnet_id in subnet_ids {
+        ensure_route_table_association(aws, &route_table_id, subnet_id)?;
+    }
     Ok(route_table_id)
 }
 
 fn ensure_default_route(aws: &AwsCli, route_table_id: &str, igw_id: &str) -> Result<()> {
+    if aws.plan_mutation(
+        PlanChange::Modify,
+        "ec2 route",
+        format!(
+            "0.0.0.0/0 via {} in route table {}",
+            igw_id, route_table_id
+        ),
+    ) {
+        return Ok(());
+    }
+
+    match aws.backend {
+        AwsBackend::Cli => ensure_default_route_cli(aws, route_table_id, igw_id),
+        AwsBackend::Sdk => ensure_default_route_sdk(aws, route_table_id, igw_id),
+    }
+}
+
+fn ensure_default_route_cli(aws: &AwsCli, route_table_id: &str, igw_id: &str) -> Result<()> {
     let args = aws_args(&[
         "ec2",
         "create-route",
@@ -3842,6 +8185,45 @@ fn ensure_default_route(aws: &AwsCli, route_table_id: &str, igw_id: &str) -> Res
     bail!("failed to create route: {}", stderr);
 }
 
+/// SDK-backed `ensure_default_route`: branches on the service error's
+/// structured code (via `ProvideErrorMetadata::meta`) instead of scraping
+/// stderr, so a localized or reworded CLI message can't break idempotency.
+fn ensure_default_route_sdk(aws: &AwsCli, route_table_id: &str, igw_id: &str) -> Result<()> {
+    use aws_sdk_ec2::error::ProvideErrorMetadata;
+
+    let runtime = sdk_runtime()?;
+    runtime.block_on(async {
+        let client = ec2_sdk_client(&aws.region).await;
+        let result = client
+            .create_route()
+            .route_table_id(route_table_id)
+            .destination_cidr_block("0.0.0.0/0")
+            .gateway_id(igw_id)
+            .send()
+            .await;
+
+        let err = match result {
+            Ok(_) => return Ok(()),
+            Err(err) => err,
+        };
+
+        let code = err.meta().code().unwrap_or_default();
+        if code != "RouteAlreadyExists" && code != "InvalidRoute.Duplicate" {
+            return Err(anyhow!(err).context("create-route (sdk)"));
+        }
+
+        client
+            .replace_route()
+            .route_table_id(route_table_id)
+            .destination_cidr_block("0.0.0.0/0")
+            .gateway_id(igw_id)
+            .send()
+            .await
+            .context("replace-route (sdk)")?;
+        Ok(())
+    })
+}
+
 fn ensure_route_table_association(
     aws: &AwsCli,
     route_table_id: &str,
@@ -3858,6 +8240,11 @@ fn ensure_route_table_association(
 
     for table in result.route_tables {
         if table.route_table_id == route_table_id {
+            aws.plan_mutation(
+                PlanChange::NoChange,
+                "ec2 route-table-association",
+                format!("subnet {} already associated with {}", subnet_id, route_table_id),
+            );
             return Ok(());
         }
         if let Some(associations) = table.associations {
@@ -3869,6 +8256,16 @@ fn ensure_route_table_association(
                     .unwrap_or(false)
                 {
                     if let Some(association_id) = association.association_id {
+                        if aws.plan_mutation(
+                            PlanChange::Modify,
+                            "ec2 route-table-association",
+                            format!(
+                                "re-associate subnet {} from {} to {}",
+                                subnet_id, association_id, route_table_id
+                            ),
+                        ) {
+                            return Ok(());
+                        }
                         let replace_args = aws_args(&[
                             "ec2",
                             "replace-route-table-association",
@@ -3885,6 +8282,21 @@ fn ensure_route_table_association(
         }
     }
 
+    if aws.plan_mutation(
+        PlanChange::Create,
+        "ec2 route-table-association",
+        format!("associate subnet {} with {}", subnet_id, route_table_id),
+    ) {
+        return Ok(());
+    }
+
+    match aws.backend {
+        AwsBackend::Cli => associate_route_table_cli(aws, route_table_id, subnet_id),
+        AwsBackend::Sdk => associate_route_table_sdk(aws, route_table_id, subnet_id),
+    }
+}
+
+fn associate_route_table_cli(aws: &AwsCli, route_table_id: &str, subnet_id: &str) -> Result<()> {
     let assoc_args = aws_args(&[
         "ec2",
         "associate-route-table",
@@ -3905,51 +8317,275 @@ fn ensure_route_table_association(
     bail!("failed to associate route table: {}", stderr.trim());
 }
 
+fn associate_route_table_sdk(aws: &AwsCli, route_table_id: &str, subnet_id: &str) -> Result<()> {
+    use aws_sdk_ec2::error::ProvideErrorMetadata;
+
+    let runtime = sdk_runtime()?;
+    runtime.block_on(async {
+        let client = ec2_sdk_client(&aws.region).await;
+        let result = client
+            .associate_route_table()
+            .route_table_id(route_table_id)
+            .subnet_id(subnet_id)
+            .send()
+            .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) if err.meta().code() == Some("Resource.AlreadyAssociated") => Ok(()),
+            Err(err) => Err(anyhow!(err).context("associate-route-table (sdk)")),
+        }
+    })
+}
+
 fn ensure_security_group(
     aws: &AwsCli,
     config: &AwsEffectiveConfig,
     vpc_id: &str,
 ) -> Result<String> {
+    let sg_name = resource_name(&config.cluster_name, "sg");
     let sg_id = if let Some(existing) = find_security_group(aws, &config.cluster_name)? {
+        aws.plan_mutation(
+            PlanChange::NoChange,
+            "ec2 security-group",
+            format!("name={} id={}", sg_name, existing),
+        );
         existing
+    } else if aws.plan_mutation(PlanChange::Create, "ec2 security-group", format!("name={}, vpc={}", sg_name, vpc_id)) {
+        format!("<planned:{}>", sg_name)
     } else {
-        let sg_name = resource_name(&config.cluster_name, "sg");
-        let tag_spec = tag_spec("security-group", &sg_name, &config.cluster_name);
-        let mut args = aws_args(&[
-            "ec2",
-            "create-security-group",
-            "--group-name",
-            &sg_name,
-            "--description",
-            "vmcli cluster security group",
-            "--vpc-id",
-            vpc_id,
-            "--tag-specifications",
-        ]);
-        args.push(tag_spec);
-        args.extend(aws_args(&["--query", "GroupId", "--output", "text"]));
-        aws.run(&args)?
+        let new_sg_id = match aws.backend {
+            AwsBackend::Cli => create_security_group_cli(aws, &sg_name, vpc_id, &config.cluster_name)?,
+            AwsBackend::Sdk => create_security_group_sdk(aws, &sg_name, vpc_id, &config.cluster_name)?,
+        };
+        wait_until(
+            &format!("new security group {} to be visible", new_sg_id),
+            RESOURCE_VISIBILITY_TIMEOUT,
+            || Ok(find_security_group(aws, &config.cluster_name)?.is_some()),
+        )?;
+        new_sg_id
     };
 
-    for port in [22, 80, 443, 9090, 9091, 9092] {
-        authorize_sg_ingress(aws, &sg_id, port)?;
-    }
+    ensure_security_group_rules(aws, &sg_id, &config.firewall)?;
 
     Ok(sg_id)
 }
 
-fn authorize_sg_ingress(aws: &AwsCli, sg_id: &str, port: u16) -> Result<()> {
+fn create_security_group_cli(aws: &AwsCli, sg_name: &str, vpc_id: &str, cluster: &str) -> Result<String> {
+    let tag_spec = tag_spec("security-group", sg_name, cluster);
     let mut args = aws_args(&[
         "ec2",
-        "authorize-security-group-ingress",
-        "--group-id",
-        sg_id,
-        "--protocol",
-        "tcp",
-        "--port",
+        "create-security-group",
+        "--group-name",
+        sg_name,
+        "--description",
+        "vmcli cluster security group",
+        "--vpc-id",
+        vpc_id,
+        "--tag-specifications",
     ]);
-    args.push(port.to_string());
-    args.extend(aws_args(&["--cidr", "0.0.0.0/0"]));
+    args.push(tag_spec);
+    args.extend(aws_args(&["--query", "GroupId", "--output", "text"]));
+    aws.run(&args)
+}
+
+fn create_security_group_sdk(aws: &AwsCli, sg_name: &str, vpc_id: &str, cluster: &str) -> Result<String> {
+    let runtime = sdk_runtime()?;
+    runtime.block_on(async {
+        let client = ec2_sdk_client(&aws.region).await;
+        let tag = aws_sdk_ec2::types::Tag::builder()
+            .key("Cluster")
+            .value(cluster)
+            .build();
+        let name_tag = aws_sdk_ec2::types::Tag::builder()
+            .key("Name")
+            .value(sg_name)
+            .build();
+        let tag_spec = aws_sdk_ec2::types::TagSpecification::builder()
+            .resource_type(aws_sdk_ec2::types::ResourceType::SecurityGroup)
+            .tags(tag)
+            .tags(name_tag)
+            .build();
+        let response = client
+            .create_security_group()
+            .group_name(sg_name)
+            .description("vmcli cluster security group")
+            .vpc_id(vpc_id)
+            .tag_specifications(tag_spec)
+            .send()
+            .await
+            .context("create-security-group (sdk)")?;
+        response
+            .group_id()
+            .map(|id| id.to_string())
+            .ok_or_else(|| anyhow!("create-security-group (sdk) did not return a GroupId"))
+    })
+}
+
+/// Diffs `desired` against the security group's live ingress permissions and
+/// issues only the `authorize`/`revoke` calls needed to converge, so editing
+/// `[[firewall]]` in config reconciles the live group instead of requiring
+/// manual `aws ec2 *-security-group-ingress` calls.
+fn ensure_security_group_rules(aws: &AwsCli, sg_id: &str, desired: &[FirewallRule]) -> Result<()> {
+    if sg_id.starts_with("<planned:") {
+        for rule in desired {
+            let (_, _, _, source) = firewall_rule_key(rule);
+            aws.plan_mutation(
+                PlanChange::Create,
+                "ec2 security-group-rule",
+                format!(
+                    "protocol={} port={} source={}",
+                    rule.protocol,
+                    firewall_port_range(rule),
+                    source
+                ),
+            );
+        }
+        return Ok(());
+    }
+
+    let security_groups = describe_security_groups_by_ids(aws, &[sg_id.to_string()])?;
+    let live = sg_rule_keys(&security_groups);
+    let desired_keys: Vec<_> = desired.iter().map(firewall_rule_key).collect();
+
+    for rule in desired {
+        let key = firewall_rule_key(rule);
+        if live.contains(&key) {
+            continue;
+        }
+        let (_, _, _, ref source) = key;
+        if aws.plan_mutation(
+            PlanChange::Create,
+            "ec2 security-group-rule",
+            format!(
+                "protocol={} port={} source={}",
+                rule.protocol,
+                firewall_port_range(rule),
+                source
+            ),
+        ) {
+            continue;
+        }
+        authorize_sg_ingress(aws, sg_id, rule)?;
+    }
+
+    for (protocol, from_port, to_port, source) in &live {
+        if desired_keys.contains(&(protocol.clone(), *from_port, *to_port, source.clone())) {
+            continue;
+        }
+        let stale = firewall_rule_from_key(protocol, *from_port, *to_port, source);
+        if aws.plan_mutation(
+            PlanChange::Modify,
+            "ec2 security-group-rule",
+            format!(
+                "revoke protocol={} port={} source={}",
+                protocol,
+                firewall_port_range(&stale),
+                source
+            ),
+        ) {
+            continue;
+        }
+        revoke_sg_ingress(aws, sg_id, &stale)?;
+    }
+
+    Ok(())
+}
+
+/// Renders a rule's port the way `aws ec2 *-security-group-ingress --port`
+/// expects: a single number, or `from-to` when `to_port` differs from `port`.
+fn firewall_port_range(rule: &FirewallRule) -> String {
+    match rule.to_port {
+        Some(to_port) if to_port != rule.port => format!("{}-{}", rule.port, to_port),
+        _ => rule.port.to_string(),
+    }
+}
+
+/// Protocol/port-range/source key used to diff a `FirewallRule` against a live
+/// AWS security-group permission, independent of whether the source is a CIDR
+/// or another security group.
+fn firewall_rule_key(rule: &FirewallRule) -> (String, u16, u16, String) {
+    let to_port = rule.to_port.unwrap_or(rule.port);
+    let source = match (rule.source_security_group.as_ref(), rule.prefix_list.as_ref()) {
+        (Some(group_id), _) => format!("sg:{}", group_id),
+        (None, Some(prefix_list_id)) => format!("pl:{}", prefix_list_id),
+        (None, None) => format!("cidr:{}", rule.cidr),
+    };
+    (rule.protocol.clone(), rule.port, to_port, source)
+}
+
+/// Reconstructs a minimal `FirewallRule` from a `firewall_rule_key` tuple, for
+/// revoking/printing a live permission that isn't in the desired config.
+fn firewall_rule_from_key(protocol: &str, from_port: u16, to_port: u16, source: &str) -> FirewallRule {
+    let (cidr, source_security_group, prefix_list) = if let Some(group_id) = source.strip_prefix("sg:") {
+        (default_firewall_cidr(), Some(group_id.to_string()), None)
+    } else if let Some(prefix_list_id) = source.strip_prefix("pl:") {
+        (default_firewall_cidr(), None, Some(prefix_list_id.to_string()))
+    } else {
+        (
+            source.strip_prefix("cidr:").unwrap_or(source).to_string(),
+            None,
+            None,
+        )
+    };
+    FirewallRule {
+        port: from_port,
+        to_port: if to_port == from_port {
+            None
+        } else {
+            Some(to_port)
+        },
+        protocol: protocol.to_string(),
+        cidr,
+        source_security_group,
+        prefix_list,
+        auto_detect_caller_ip: false,
+        preset: None,
+        description: None,
+    }
+}
+
+/// Builds the `--protocol`/`--port`/source arguments shared by
+/// `authorize`/`revoke-security-group-ingress`. A CIDR or security-group
+/// source uses the simple shorthand flags; a prefix-list source has no
+/// shorthand flag in the `aws` CLI, so it goes through `--ip-permissions` JSON.
+fn sg_ingress_cli_source_args(rule: &FirewallRule) -> Vec<String> {
+    match rule.prefix_list.as_ref() {
+        Some(prefix_list_id) => vec![
+            "--ip-permissions".to_string(),
+            format!(
+                "[{{\"IpProtocol\":\"{}\",\"FromPort\":{},\"ToPort\":{},\"PrefixListIds\":[{{\"PrefixListId\":\"{}\"}}]}}]",
+                rule.protocol,
+                rule.port,
+                rule.to_port.unwrap_or(rule.port),
+                prefix_list_id
+            ),
+        ],
+        None => {
+            let mut args = aws_args(&["--protocol", &rule.protocol, "--port"]);
+            args.push(firewall_port_range(rule));
+            match rule.source_security_group.as_ref() {
+                Some(group_id) => args.extend(aws_args(&["--source-group", group_id])),
+                None => args.extend(aws_args(&["--cidr", &rule.cidr])),
+            }
+            args
+        }
+    }
+}
+
+fn authorize_sg_ingress(aws: &AwsCli, sg_id: &str, rule: &FirewallRule) -> Result<()> {
+    if aws.dry_run {
+        return Ok(());
+    }
+    match aws.backend {
+        AwsBackend::Cli => authorize_sg_ingress_cli(aws, sg_id, rule),
+        AwsBackend::Sdk => authorize_sg_ingress_sdk(aws, sg_id, rule),
+    }
+}
+
+fn authorize_sg_ingress_cli(aws: &AwsCli, sg_id: &str, rule: &FirewallRule) -> Result<()> {
+    let mut args = aws_args(&["ec2", "authorize-security-group-ingress", "--group-id", sg_id]);
+    args.extend(sg_ingress_cli_source_args(rule));
     let output = aws.run_output(&args)?;
     if output.status.success() {
         return Ok(());
@@ -3965,9 +8601,184 @@ fn authorize_sg_ingress(aws: &AwsCli, sg_id: &str, port: u16) -> Result<()> {
     );
 }
 
+fn authorize_sg_ingress_sdk(aws: &AwsCli, sg_id: &str, rule: &FirewallRule) -> Result<()> {
+    use aws_sdk_ec2::error::ProvideErrorMetadata;
+    let runtime = sdk_runtime()?;
+    runtime.block_on(async {
+        let client = ec2_sdk_client(&aws.region).await;
+        let (from_port, to_port) = (rule.port as i32, rule.to_port.unwrap_or(rule.port) as i32);
+        let mut permission = aws_sdk_ec2::types::IpPermission::builder()
+            .ip_protocol(&rule.protocol)
+            .from_port(from_port)
+            .to_port(to_port);
+        permission = match (rule.source_security_group.as_ref(), rule.prefix_list.as_ref()) {
+            (Some(group_id), _) => permission.user_id_group_pairs(
+                aws_sdk_ec2::types::UserIdGroupPair::builder()
+                    .group_id(group_id)
+                    .build(),
+            ),
+            (None, Some(prefix_list_id)) => permission.prefix_list_ids(
+                aws_sdk_ec2::types::PrefixListId::builder()
+                    .prefix_list_id(prefix_list_id)
+                    .build(),
+            ),
+            (None, None) => permission.ip_ranges(
+                aws_sdk_ec2::types::IpRange::builder()
+                    .cidr_ip(&rule.cidr)
+                    .build(),
+            ),
+        };
+        let result = client
+            .authorize_security_group_ingress()
+            .group_id(sg_id)
+            .ip_permissions(permission.build())
+            .send()
+            .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) if err.meta().code() == Some("InvalidPermission.Duplicate") => Ok(()),
+            Err(err) => Err(anyhow!(err).context("authorize-security-group-ingress (sdk)")),
+        }
+    })
+}
+
+fn revoke_sg_ingress(aws: &AwsCli, sg_id: &str, rule: &FirewallRule) -> Result<()> {
+    match aws.backend {
+        AwsBackend::Cli => revoke_sg_ingress_cli(aws, sg_id, rule),
+        AwsBackend::Sdk => revoke_sg_ingress_sdk(aws, sg_id, rule),
+    }
+}
+
+fn revoke_sg_ingress_cli(aws: &AwsCli, sg_id: &str, rule: &FirewallRule) -> Result<()> {
+    let mut args = aws_args(&["ec2", "revoke-security-group-ingress", "--group-id", sg_id]);
+    args.extend(sg_ingress_cli_source_args(rule));
+    let output = aws.run_output(&args)?;
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if stderr.contains("InvalidPermission.NotFound") {
+        return Ok(());
+    }
+    bail!(
+        "failed to revoke security group ingress: {}",
+        stderr.trim()
+    );
+}
+
+fn revoke_sg_ingress_sdk(aws: &AwsCli, sg_id: &str, rule: &FirewallRule) -> Result<()> {
+    use aws_sdk_ec2::error::ProvideErrorMetadata;
+    let runtime = sdk_runtime()?;
+    runtime.block_on(async {
+        let client = ec2_sdk_client(&aws.region).await;
+        let (from_port, to_port) = (rule.port as i32, rule.to_port.unwrap_or(rule.port) as i32);
+        let mut permission = aws_sdk_ec2::types::IpPermission::builder()
+            .ip_protocol(&rule.protocol)
+            .from_port(from_port)
+            .to_port(to_port);
+        permission = match (rule.source_security_group.as_ref(), rule.prefix_list.as_ref()) {
+            (Some(group_id), _) => permission.user_id_group_pairs(
+                aws_sdk_ec2::types::UserIdGroupPair::builder()
+                    .group_id(group_id)
+                    .build(),
+            ),
+            (None, Some(prefix_list_id)) => permission.prefix_list_ids(
+                aws_sdk_ec2::types::PrefixListId::builder()
+                    .prefix_list_id(prefix_list_id)
+                    .build(),
+            ),
+            (None, None) => permission.ip_ranges(
+                aws_sdk_ec2::types::IpRange::builder()
+                    .cidr_ip(&rule.cidr)
+                    .build(),
+            ),
+        };
+        let result = client
+            .revoke_security_group_ingress()
+            .group_id(sg_id)
+            .ip_permissions(permission.build())
+            .send()
+            .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) if err.meta().code() == Some("InvalidPermission.NotFound") => Ok(()),
+            Err(err) => Err(anyhow!(err).context("revoke-security-group-ingress (sdk)")),
+        }
+    })
+}
+
+/// Flattens a live security group's `IpPermissions` into `firewall_rule_key`
+/// tuples comparable against `FirewallRule`, so rule reconciliation can diff
+/// desired vs. actual state without caring about AWS's nested permission shape.
+fn sg_rule_keys(security_groups: &[SecurityGroup]) -> Vec<(String, u16, u16, String)> {
+    let mut keys = Vec::new();
+    for sg in security_groups {
+        let Some(permissions) = sg.ip_permissions.as_ref() else {
+            continue;
+        };
+        for permission in permissions {
+            let protocol = permission
+                .ip_protocol
+                .as_deref()
+                .unwrap_or_default()
+                .to_ascii_lowercase();
+            let Some(from_port) = permission.from_port else {
+                continue;
+            };
+            let to_port = permission.to_port.unwrap_or(from_port);
+            if let Some(ranges) = permission.ip_ranges.as_ref() {
+                for range in ranges {
+                    let Some(cidr) = range.cidr_ip.as_ref() else {
+                        continue;
+                    };
+                    keys.push((
+                        protocol.clone(),
+                        from_port as u16,
+                        to_port as u16,
+                        format!("cidr:{}", cidr),
+                    ));
+                }
+            }
+            if let Some(pairs) = permission.user_id_group_pairs.as_ref() {
+                for pair in pairs {
+                    let Some(group_id) = pair.group_id.as_ref() else {
+                        continue;
+                    };
+                    keys.push((
+                        protocol.clone(),
+                        from_port as u16,
+                        to_port as u16,
+                        format!("sg:{}", group_id),
+                    ));
+                }
+            }
+            if let Some(prefix_list_ids) = permission.prefix_list_ids.as_ref() {
+                for entry in prefix_list_ids {
+                    let Some(prefix_list_id) = entry.prefix_list_id.as_ref() else {
+                        continue;
+                    };
+                    keys.push((
+                        protocol.clone(),
+                        from_port as u16,
+                        to_port as u16,
+                        format!("pl:{}", prefix_list_id),
+                    ));
+                }
+            }
+        }
+    }
+    keys
+}
+
 fn ensure_key_pair(aws: &AwsCli, config: &AwsEffectiveConfig) -> Result<String> {
     let key_name = resource_name(&config.cluster_name, "key");
     if key_pair_exists(aws, &key_name)? {
+        aws.plan_mutation(PlanChange::NoChange, "ec2 key-pair", format!("name={}", key_name));
+        return Ok(key_name);
+    }
+
+    if aws.plan_mutation(PlanChange::Create, "ec2 key-pair", format!("name={}", key_name)) {
         return Ok(key_name);
     }
 
@@ -3985,6 +8796,11 @@ fn ensure_key_pair(aws: &AwsCli, config: &AwsEffectiveConfig) -> Result<String>
     args.extend(aws_args(&["--tag-specifications"]));
     args.push(tag_spec);
     let _ = aws.run(&args)?;
+    wait_until(
+        &format!("new key pair {} to be visible", key_name),
+        RESOURCE_VISIBILITY_TIMEOUT,
+        || key_pair_exists(aws, &key_name),
+    )?;
     Ok(key_name)
 }
 
@@ -4030,6 +8846,18 @@ fn resolve_ami_id(aws: &AwsCli, config: &AwsEffectiveConfig) -> Result<String> {
         return Ok(ami_id.clone());
     }
 
+    let ami_id = match aws.backend {
+        AwsBackend::Cli => resolve_ami_id_cli(aws)?,
+        AwsBackend::Sdk => resolve_ami_id_sdk(aws)?,
+    };
+    if ami_id.trim().is_empty() {
+        bail!("resolved AMI id is empty")
+    } else {
+        Ok(ami_id)
+    }
+}
+
+fn resolve_ami_id_cli(aws: &AwsCli) -> Result<String> {
     let args = aws_args(&[
         "ssm",
         "get-parameter",
@@ -4040,12 +8868,25 @@ fn resolve_ami_id(aws: &AwsCli, config: &AwsEffectiveConfig) -> Result<String> {
         "--output",
         "text",
     ]);
-    let ami_id = aws.run(&args)?;
-    if ami_id.trim().is_empty() {
-        bail!("resolved AMI id is empty")
-    } else {
-        Ok(ami_id)
-    }
+    aws.run(&args)
+}
+
+fn resolve_ami_id_sdk(aws: &AwsCli) -> Result<String> {
+    let runtime = sdk_runtime()?;
+    runtime.block_on(async {
+        let client = ssm_sdk_client(&aws.region).await;
+        let response = client
+            .get_parameter()
+            .name(UBUNTU_2404_AMI_SSM)
+            .send()
+            .await
+            .context("get-parameter (sdk)")?;
+        response
+            .parameter()
+            .and_then(|parameter| parameter.value())
+            .map(|value| value.to_string())
+            .ok_or_else(|| anyhow!("get-parameter (sdk) did not return a value"))
+    })
 }
 
 fn disassociate_route_table(aws: &AwsCli, route_table: &RouteTable) -> Result<()> {
@@ -4266,6 +9107,13 @@ fn describe_instances_by_vpc(aws: &AwsCli, vpc_id: &str) -> Result<Vec<Instance>
 }
 
 fn describe_instances(aws: &AwsCli, filters: &[String]) -> Result<Vec<Instance>> {
+    match aws.backend {
+        AwsBackend::Cli => describe_instances_cli(aws, filters),
+        AwsBackend::Sdk => describe_instances_sdk(aws, filters),
+    }
+}
+
+fn describe_instances_cli(aws: &AwsCli, filters: &[String]) -> Result<Vec<Instance>> {
     let mut args = aws_args(&["ec2", "describe-instances", "--output", "json"]);
     append_filters(&mut args, filters);
     let output = aws.run(&args)?;
@@ -4278,6 +9126,26 @@ fn describe_instances(aws: &AwsCli, filters: &[String]) -> Result<Vec<Instance>>
     Ok(instances)
 }
 
+fn describe_instances_sdk(aws: &AwsCli, filters: &[String]) -> Result<Vec<Instance>> {
+    let runtime = sdk_runtime()?;
+    runtime.block_on(async {
+        let client = ec2_sdk_client(&aws.region).await;
+        let response = client
+            .describe_instances()
+            .set_filters(Some(parse_ec2_cli_filters(filters)))
+            .send()
+            .await
+            .context("describe-instances (sdk)")?;
+        let mut instances = Vec::new();
+        for reservation in response.reservations() {
+            for instance in reservation.instances() {
+                instances.push(convert_sdk_instance(instance));
+            }
+        }
+        Ok(instances)
+    })
+}
+
 fn describe_security_groups_by_ids(
     aws: &AwsCli,
     group_ids: &[String],
@@ -4286,6 +9154,16 @@ fn describe_security_groups_by_ids(
         return Ok(Vec::new());
     }
 
+    match aws.backend {
+        AwsBackend::Cli => describe_security_groups_by_ids_cli(aws, group_ids),
+        AwsBackend::Sdk => describe_security_groups_by_ids_sdk(aws, group_ids),
+    }
+}
+
+fn describe_security_groups_by_ids_cli(
+    aws: &AwsCli,
+    group_ids: &[String],
+) -> Result<Vec<SecurityGroup>> {
     let mut args = aws_args(&[
         "ec2",
         "describe-security-groups",
@@ -4300,6 +9178,169 @@ fn describe_security_groups_by_ids(
     Ok(result.security_groups)
 }
 
+fn describe_security_groups_by_ids_sdk(
+    aws: &AwsCli,
+    group_ids: &[String],
+) -> Result<Vec<SecurityGroup>> {
+    let runtime = sdk_runtime()?;
+    runtime.block_on(async {
+        let client = ec2_sdk_client(&aws.region).await;
+        let response = client
+            .describe_security_groups()
+            .set_group_ids(Some(group_ids.to_vec()))
+            .send()
+            .await
+            .context("describe-security-groups (sdk)")?;
+        Ok(response
+            .security_groups()
+            .iter()
+            .map(convert_sdk_security_group)
+            .collect())
+    })
+}
+
+fn sdk_runtime() -> Result<tokio::runtime::Runtime> {
+    tokio::runtime::Runtime::new().context("create tokio runtime for aws sdk calls")
+}
+
+async fn ec2_sdk_client(region: &str) -> aws_sdk_ec2::Client {
+    let shared_config = aws_config::from_env()
+        .region(aws_sdk_ec2::config::Region::new(region.to_string()))
+        .load()
+        .await;
+    aws_sdk_ec2::Client::new(&shared_config)
+}
+
+async fn ssm_sdk_client(region: &str) -> aws_sdk_ssm::Client {
+    let shared_config = aws_config::from_env()
+        .region(aws_sdk_ssm::config::Region::new(region.to_string()))
+        .load()
+        .await;
+    aws_sdk_ssm::Client::new(&shared_config)
+}
+
+fn parse_ec2_cli_filters(filters: &[String]) -> Vec<aws_sdk_ec2::types::Filter> {
+    filters
+        .iter()
+        .filter_map(|filter| {
+            let rest = filter.strip_prefix("Name=")?;
+            let (name, values) = rest.split_once(",Values=")?;
+            let values = values.split(',').map(|value| value.to_string()).collect();
+            Some(
+                aws_sdk_ec2::types::Filter::builder()
+                    .name(name)
+                    .set_values(Some(values))
+                    .build(),
+            )
+        })
+        .collect()
+}
+
+fn convert_sdk_instance(instance: &aws_sdk_ec2::types::Instance) -> Instance {
+    Instance {
+        instance_id: instance.instance_id().unwrap_or_default().to_string(),
+        state: InstanceState {
+            name: instance
+                .state()
+                .and_then(|state| state.name())
+                .map(|name| name.as_str().to_string())
+                .unwrap_or_else(|| "unknown".to_string()),
+        },
+        placement: instance.placement().map(|placement| InstancePlacement {
+            availability_zone: placement.availability_zone().map(|value| value.to_string()),
+        }),
+        vpc_id: instance.vpc_id().map(|value| value.to_string()),
+        subnet_id: instance.subnet_id().map(|value| value.to_string()),
+        public_ip: instance.public_ip_address().map(|value| value.to_string()),
+        private_ip: instance.private_ip_address().map(|value| value.to_string()),
+        security_groups: Some(
+            instance
+                .security_groups()
+                .iter()
+                .map(|group| InstanceSecurityGroupRef {
+                    group_id: group.group_id().map(|value| value.to_string()),
+                })
+                .collect(),
+        ),
+        tags: Some(
+            instance
+                .tags()
+                .iter()
+                .map(|tag| Tag {
+                    key: tag.key().unwrap_or_default().to_string(),
+                    value: tag.value().unwrap_or_default().to_string(),
+                })
+                .collect(),
+        ),
+        instance_lifecycle: instance
+            .instance_lifecycle()
+            .map(|lifecycle| lifecycle.as_str().to_string()),
+        state_reason: instance
+            .state_reason()
+            .and_then(|reason| reason.code())
+            .map(|code| InstanceStateReason {
+                code: code.to_string(),
+            }),
+    }
+}
+
+fn convert_sdk_security_group(group: &aws_sdk_ec2::types::SecurityGroup) -> SecurityGroup {
+    SecurityGroup {
+        group_id: group.group_id().unwrap_or_default().to_string(),
+        ip_permissions: Some(
+            group
+                .ip_permissions()
+                .iter()
+                .map(convert_sdk_ip_permission)
+                .collect(),
+        ),
+    }
+}
+
+fn convert_sdk_ip_permission(permission: &aws_sdk_ec2::types::IpPermission) -> IpPermission {
+    IpPermission {
+        ip_protocol: permission.ip_protocol().map(|value| value.to_string()),
+        from_port: permission.from_port().map(|value| value as i64),
+        to_port: permission.to_port().map(|value| value as i64),
+        ip_ranges: Some(
+            permission
+                .ip_ranges()
+                .iter()
+                .map(|range| IpRange {
+                    cidr_ip: range.cidr_ip().map(|value| value.to_string()),
+                })
+                .collect(),
+        ),
+        ipv6_ranges: Some(
+            permission
+                .ipv6_ranges()
+                .iter()
+                .map(|range| Ipv6Range {
+                    cidr_ipv6: range.cidr_ipv6().map(|value| value.to_string()),
+                })
+                .collect(),
+        ),
+        user_id_group_pairs: Some(
+            permission
+                .user_id_group_pairs()
+                .iter()
+                .map(|pair| UserIdGroupPair {
+                    group_id: pair.group_id().map(|value| value.to_string()),
+                })
+                .collect(),
+        ),
+        prefix_list_ids: Some(
+            permission
+                .prefix_list_ids()
+                .iter()
+                .map(|entry| PrefixListId {
+                    prefix_list_id: entry.prefix_list_id().map(|value| value.to_string()),
+                })
+                .collect(),
+        ),
+    }
+}
+
 fn describe_ec2_status_checks(
     aws: &AwsCli,
     instance_id: &str,
@@ -4544,6 +9585,125 @@ fn is_access_denied_error(message: &str) -> bool {
         || lower.contains("not authorized")
 }
 
+/// Matches the AWS error codes/messages that indicate a transient,
+/// safe-to-retry failure: API throttling or an AWS-side internal hiccup.
+/// Anything else (bad arguments, resource-not-found, permissions) is left to
+/// fail immediately rather than retried.
+fn is_retryable_aws_error(message: &str) -> bool {
+    let lower = message.to_ascii_lowercase();
+    lower.contains("requestlimitexceeded")
+        || lower.contains("throttling")
+        || lower.contains("toomanyrequestsexception")
+        || lower.contains("internalerror")
+        || lower.contains("internalfailure")
+        || lower.contains("requesttimeout")
+        || lower.contains("serviceunavailable")
+}
+
+/// Matches the AWS error codes/messages that indicate a spot request was
+/// rejected for price/capacity reasons: the only case `--spot-fallback-on-demand`
+/// should retry on-demand for. Anything else (bad AMI, duplicate name,
+/// malformed arguments) would just fail identically on the on-demand retry,
+/// so it's left to propagate as-is.
+fn is_spot_capacity_error(message: &str) -> bool {
+    let lower = message.to_ascii_lowercase();
+    lower.contains("spotmaxpricetoolow")
+        || lower.contains("insufficientinstancecapacity")
+        || lower.contains("maxspotinstancecountexceeded")
+        || lower.contains("spotinstancecountlimitexceeded")
+        || lower.contains("capacity-not-available")
+        || lower.contains("unfulfillable")
+}
+
+/// Cheap, non-cryptographic jitter in `[0, max]` derived from the system
+/// clock, used to desynchronize retries across concurrent
+/// `run_with_concurrency` workers so they don't all hammer AWS on the same
+/// backoff tick.
+fn jitter(max: Duration) -> Duration {
+    if max.is_zero() {
+        return Duration::ZERO;
+    }
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    max.mul_f64(f64::from(nanos) / f64::from(999_999_999u32))
+}
+
+/// Polls `check` with exponential backoff + jitter until it returns `Ok(true)`
+/// or `timeout` elapses. Creation helpers use this to confirm a just-created
+/// resource (route table, security group, key pair, ...) is visible to a
+/// subsequent `describe-*` call before anything downstream depends on it,
+/// rather than assuming AWS has already propagated it.
+fn wait_until<F>(what: &str, timeout: Duration, mut check: F) -> Result<()>
+where
+    F: FnMut() -> Result<bool>,
+{
+    let deadline = Instant::now() + timeout;
+    let mut delay = Duration::from_millis(500);
+    loop {
+        if check()? {
+            return Ok(());
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            bail!("timed out waiting for {} to become visible", what);
+        }
+        let wait = delay.min(deadline - now);
+        sleep(wait + jitter(wait));
+        delay = (delay * 2).min(Duration::from_secs(10));
+    }
+}
+
+/// Issues the `ec2-instance-connect send-ssh-public-key` call that injects a
+/// ~60s ephemeral public key for `os_user`, bailing unless AWS confirms
+/// `Success`. Used by `ec2 ssh`, which (unlike `run_eic_probe`) wants a hard
+/// failure rather than a probe result when the push doesn't go through.
+#[allow(clippy::too_many_arguments)]
+fn send_ephemeral_ssh_public_key(
+    aws: &AwsCli,
+    config_root: &Path,
+    cluster: &str,
+    name: &str,
+    instance_id: &str,
+    os_user: &str,
+    availability_zone: &str,
+    public_key_path: &Path,
+) -> Result<()> {
+    let mut args = aws_args(&[
+        "ec2-instance-connect",
+        "send-ssh-public-key",
+        "--instance-id",
+    ]);
+    args.push(instance_id.to_string());
+    args.extend(aws_args(&["--instance-os-user"]));
+    args.push(os_user.to_string());
+    args.extend(aws_args(&["--availability-zone"]));
+    args.push(availability_zone.to_string());
+    args.extend(aws_args(&["--ssh-public-key"]));
+    args.push(format!("file://{}", public_key_path.display()));
+    args.extend(aws_args(&["--output", "json"]));
+
+    let result = aws.run(&args);
+    record_audit_event(
+        config_root,
+        EC2_PROVIDER,
+        cluster,
+        Some(name),
+        Some(instance_id),
+        "send-ssh-public-key",
+        &args,
+        &result,
+    );
+    let output = result?;
+    let parsed: EicSendSshPublicKeyResponse =
+        serde_json::from_str(&output).context("parse ec2-instance-connect send-ssh-public-key")?;
+    if !parsed.success.unwrap_or(false) {
+        bail!("ec2-instance-connect send-ssh-public-key returned success=false");
+    }
+    Ok(())
+}
+
 fn run_eic_probe(
     aws: &AwsCli,
     config: &AwsEffectiveConfig,
@@ -4623,10 +9783,89 @@ fn run_eic_probe(
     Ok(result)
 }
 
+/// Classifies a failed TCP dial as `connection-refused` (something answered
+/// and said no, i.e. likely no sshd listening) or `timed-out` (nothing
+/// answered at all, i.e. likely a network/route/firewall problem), falling
+/// back to the raw error for anything else.
+fn tcp_connect_error_reason(err: &io::Error) -> String {
+    match err.kind() {
+        io::ErrorKind::ConnectionRefused => "connection-refused".to_string(),
+        io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock => "timed-out".to_string(),
+        _ => err.to_string(),
+    }
+}
+
+/// Directly dials `public_ip:22` to measure SSH reachability, as a ground
+/// truth complementing `run_eic_probe`'s AWS-control-plane-only view. Two
+/// independent checks, each with its own fresh connection and the same
+/// bounded `connect_timeout`: reading the raw `SSH-2.0-...` banner line, and
+/// completing a full `ssh2` protocol handshake.
+fn run_tcp_ssh_probe(public_ip: Option<&str>, connect_timeout: Duration) -> TcpSshProbeResult {
+    let Some(public_ip) = public_ip.filter(|ip| !ip.trim().is_empty()) else {
+        let reason = Some("no-public-ip".to_string());
+        return TcpSshProbeResult {
+            tcp_ssh_banner: ProbeOutcome::Skipped,
+            tcp_ssh_banner_reason: reason.clone(),
+            ssh_handshake: ProbeOutcome::Skipped,
+            ssh_handshake_reason: reason,
+        };
+    };
+
+    let addr = format!("{}:22", public_ip);
+    let socket_addr = match addr.to_socket_addrs().ok().and_then(|mut it| it.next()) {
+        Some(socket_addr) => socket_addr,
+        None => {
+            let reason = Some(format!("could not resolve address {}", addr));
+            return TcpSshProbeResult {
+                tcp_ssh_banner: ProbeOutcome::Failed,
+                tcp_ssh_banner_reason: reason.clone(),
+                ssh_handshake: ProbeOutcome::Failed,
+                ssh_handshake_reason: reason,
+            };
+        }
+    };
+
+    let (tcp_ssh_banner, tcp_ssh_banner_reason) =
+        match TcpStream::connect_timeout(&socket_addr, connect_timeout).and_then(|stream| {
+            stream.set_read_timeout(Some(connect_timeout))?;
+            let mut reader = io::BufReader::new(stream);
+            let mut banner = String::new();
+            reader.read_line(&mut banner)?;
+            let banner = banner.trim().to_string();
+            if banner.is_empty() {
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "empty banner"));
+            }
+            Ok(banner)
+        }) {
+            Ok(banner) => (ProbeOutcome::Success, Some(banner)),
+            Err(err) => (ProbeOutcome::Failed, Some(tcp_connect_error_reason(&err))),
+        };
+
+    let (ssh_handshake, ssh_handshake_reason) = match TcpStream::connect_timeout(&socket_addr, connect_timeout) {
+        Ok(tcp) => match Session::new().and_then(|mut session| {
+            session.set_tcp_stream(tcp);
+            session.handshake()?;
+            Ok(())
+        }) {
+            Ok(()) => (ProbeOutcome::Success, None),
+            Err(err) => (ProbeOutcome::Failed, Some(err.to_string())),
+        },
+        Err(err) => (ProbeOutcome::Failed, Some(tcp_connect_error_reason(&err))),
+    };
+
+    TcpSshProbeResult {
+        tcp_ssh_banner,
+        tcp_ssh_banner_reason,
+        ssh_handshake,
+        ssh_handshake_reason,
+    }
+}
+
 fn summarize_health(
     instance_state: &str,
     ec2_checks_pass: Option<bool>,
     eic_probe: &EicProbeResult,
+    tcp_probe: &TcpSshProbeResult,
 ) -> HealthSummary {
     if instance_state != "running" {
         return HealthSummary {
@@ -4647,6 +9886,18 @@ fn summarize_health(
     let remote_probe_success = eic_probe.send_ssh_public_key == ProbeOutcome::Success;
 
     if ec2_checks_pass == Some(true) && remote_probe_success {
+        if tcp_probe.tcp_ssh_banner == ProbeOutcome::Failed
+            && tcp_probe.tcp_ssh_banner_reason.as_deref() == Some("timed-out")
+        {
+            return HealthSummary {
+                level: HealthLevel::Degraded,
+                ssh_local_problem_likely: Some(false),
+                notes: "control-plane-ok-but-tcp-22-unreachable".to_string(),
+            };
+        }
+        // The AWS control plane and a direct dial agree the instance is
+        // reachable, so an SSH failure on top of this is almost certainly a
+        // local config problem (bad key, wrong user) rather than networking.
         return HealthSummary {
             level: HealthLevel::Ok,
             ssh_local_problem_likely: Some(true),
@@ -4695,6 +9946,7 @@ fn print_health_report(
     instance: &Instance,
     ec2_checks: &Ec2StatusChecks,
     eic_probe: &EicProbeResult,
+    tcp_probe: &TcpSshProbeResult,
     summary: &HealthSummary,
 ) {
     let resolved_name =
@@ -4743,28 +9995,166 @@ fn print_health_report(
         println!("eic.send-ssh-public-key-reason={}", one_line_value(reason));
     }
 
-    println!("summary.health={}", summary.level.as_str());
-    println!(
-        "summary.ssh-local-problem-likely={}",
-        tri_bool_to_str(summary.ssh_local_problem_likely)
-    );
-    println!("summary.notes={}", summary.notes);
+    println!("tcp.ssh-banner={}", tcp_probe.tcp_ssh_banner.as_str());
+    if let Some(reason) = tcp_probe.tcp_ssh_banner_reason.as_deref() {
+        println!("tcp.ssh-banner-reason={}", one_line_value(reason));
+    }
+    println!("tcp.ssh-handshake={}", tcp_probe.ssh_handshake.as_str());
+    if let Some(reason) = tcp_probe.ssh_handshake_reason.as_deref() {
+        println!("tcp.ssh-handshake-reason={}", one_line_value(reason));
+    }
+
+    println!("summary.health={}", summary.level.as_str());
+    println!(
+        "summary.ssh-local-problem-likely={}",
+        tri_bool_to_str(summary.ssh_local_problem_likely)
+    );
+    println!("summary.notes={}", summary.notes);
+}
+
+/// One append-only record of a mutating action taken against a provider
+/// (launch, terminate, reboot, EIC key push, ...), written as a single JSON
+/// line by `record_audit_event`. Kept flat and serializable as-is, with no
+/// nested enums, so it can later be shipped to a time-series/SQL store
+/// without a bespoke flattening step.
+#[derive(Debug, Serialize)]
+struct AuditRecord {
+    timestamp: u64,
+    provider: String,
+    cluster: String,
+    name: Option<String>,
+    instance_id: Option<String>,
+    action: String,
+    args: Vec<String>,
+    outcome: String,
+    error: Option<String>,
+}
+
+/// The audit log path: `[audit_log_path]` from the global config if set,
+/// otherwise `audit.jsonl` under `config_root`.
+fn resolve_audit_log_path(config_root: &Path) -> Result<PathBuf> {
+    let global_config = load_global_config(config_root)?;
+    match global_config.audit_log_path {
+        Some(path) => expand_home_path(&path),
+        None => Ok(config_root.join(AUDIT_LOG_FILE_NAME)),
+    }
+}
+
+/// Flag names whose following argument may carry secrets (cloud-init
+/// user-data routinely embeds bootstrap credentials/tokens) and so must
+/// never reach the audit log verbatim.
+const AUDIT_REDACTED_FLAGS: &[&str] = &["--user-data"];
+
+/// Replaces the value following any flag in `AUDIT_REDACTED_FLAGS` with a
+/// placeholder, so `record_audit_event` never persists secret-bearing
+/// payloads to the audit trail.
+fn redact_audit_args(args: &[String]) -> Vec<String> {
+    let mut redacted = Vec::with_capacity(args.len());
+    let mut redact_next = false;
+    for arg in args {
+        if redact_next {
+            redacted.push("<redacted>".to_string());
+            redact_next = false;
+            continue;
+        }
+        redact_next = AUDIT_REDACTED_FLAGS.contains(&arg.as_str());
+        redacted.push(arg.clone());
+    }
+    redacted
+}
+
+/// Appends one `AuditRecord` to the audit log. Best-effort: a failure to
+/// write the audit trail (e.g. a read-only config dir) is reported as a
+/// warning rather than failing the caller's action, since by the time this
+/// runs the action has already happened against the provider.
+#[allow(clippy::too_many_arguments)]
+fn record_audit_event<T>(
+    config_root: &Path,
+    provider: &str,
+    cluster: &str,
+    name: Option<&str>,
+    instance_id: Option<&str>,
+    action: &str,
+    args: &[String],
+    result: &Result<T>,
+) {
+    let (outcome, error) = match result {
+        Ok(_) => ("success", None),
+        Err(err) => ("error", Some(format!("{:#}", err))),
+    };
+    let record = AuditRecord {
+        timestamp: unix_timestamp_now().unwrap_or(0),
+        provider: provider.to_string(),
+        cluster: cluster.to_string(),
+        name: name.map(|s| s.to_string()),
+        instance_id: instance_id.map(|s| s.to_string()),
+        action: action.to_string(),
+        args: redact_audit_args(args),
+        outcome: outcome.to_string(),
+        error,
+    };
+    if let Err(err) = append_audit_record(config_root, &record) {
+        eprintln!("warning: failed to write audit log entry: {:#}", err);
+    }
+}
+
+fn append_audit_record(config_root: &Path, record: &AuditRecord) -> Result<()> {
+    let path = resolve_audit_log_path(config_root)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("create audit log dir {}", parent.display()))?;
+    }
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("open audit log {}", path.display()))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut permissions = fs::metadata(&path)
+            .with_context(|| format!("stat {}", path.display()))?
+            .permissions();
+        permissions.set_mode(0o600);
+        fs::set_permissions(&path, permissions)
+            .with_context(|| format!("chmod 0600 {}", path.display()))?;
+    }
+    let line = serde_json::to_string(record).context("serialize audit record")?;
+    writeln!(file, "{}", line).with_context(|| format!("append audit log {}", path.display()))?;
+    Ok(())
+}
+
+struct SpotOptions<'a> {
+    max_price: Option<&'a str>,
+    interruption_behavior: SpotInterruptionBehavior,
+    persistent: bool,
 }
 
+#[allow(clippy::too_many_arguments)]
 fn launch_instance(
     aws: &AwsCli,
     config: &AwsEffectiveConfig,
+    config_root: &Path,
     name: &str,
     ami_id: &str,
     instance_type: &str,
     subnet_id: &str,
     sg_id: &str,
     key_name: &str,
+    user_data: Option<&str>,
+    spot: Option<SpotOptions>,
+    expire_at: Option<u64>,
+    placement_group: Option<&str>,
 ) -> Result<String> {
-    let tag_spec = format!(
-        "ResourceType=instance,Tags=[{{Key=Name,Value={}}},{{Key=Cluster,Value={}}}]",
+    let mut tag_spec = format!(
+        "ResourceType=instance,Tags=[{{Key=Name,Value={}}},{{Key=Cluster,Value={}}}",
         name, config.cluster_name
     );
+    if let Some(expire_at) = expire_at {
+        tag_spec.push_str(&format!(",{{Key=VmcliExpireAt,Value={}}}", expire_at));
+    }
+    tag_spec.push(']');
+
     let mut args = aws_args(&[
         "ec2",
         "run-instances",
@@ -4783,25 +10173,147 @@ fn launch_instance(
         "--tag-specifications",
     ]);
     args.push(tag_spec);
+    if let Some(user_data) = user_data {
+        args.extend(aws_args(&["--user-data"]));
+        args.push(user_data.to_string());
+    }
+    if let Some(spot) = spot {
+        let spot_type = if spot.persistent { "persistent" } else { "one-time" };
+        let max_price_field = match spot.max_price {
+            Some(max_price) => format!(",\"MaxPrice\":\"{}\"", max_price),
+            None => String::new(),
+        };
+        let spot_options = format!(
+            "{{\"MarketType\":\"spot\",\"SpotOptions\":{{\"SpotInstanceType\":\"{}\",\"InstanceInterruptionBehavior\":\"{}\"{}}}}}",
+            spot_type,
+            spot.interruption_behavior.as_str(),
+            max_price_field
+        );
+        args.push("--instance-market-options".to_string());
+        args.push(spot_options);
+    }
+    if let Some(placement_group) = placement_group {
+        args.push("--placement".to_string());
+        args.push(format!("GroupName={}", placement_group));
+    }
     args.extend(aws_args(&[
         "--query",
         "Instances[0].InstanceId",
         "--output",
         "text",
     ]));
-    aws.run(&args)
+    let result = aws.run(&args).context(
+        "run-instances failed; if this was a spot request consider retrying with --spot omitted for on-demand capacity",
+    );
+    record_audit_event(
+        config_root,
+        EC2_PROVIDER,
+        &config.cluster_name,
+        Some(name),
+        result.as_ref().ok().map(|instance_id| instance_id.as_str()),
+        "launch",
+        &args,
+        &result,
+    );
+    result
+}
+
+/// Polls `describe-instances` until the instance reaches `target_state`,
+/// backing off exponentially with jitter between polls. Used instead of
+/// `aws ec2 wait` so the timeout is configurable per caller and retries go
+/// through `AwsCli::run`'s throttling backoff.
+fn wait_for_instance_state(
+    aws: &AwsCli,
+    instance_id: &str,
+    target_state: &str,
+    timeout: Duration,
+) -> Result<()> {
+    let deadline = Instant::now() + timeout;
+    let mut delay = Duration::from_secs(2);
+    let mut last_state = "unknown".to_string();
+    loop {
+        let args = aws_args(&[
+            "ec2",
+            "describe-instances",
+            "--instance-ids",
+            instance_id,
+            "--output",
+            "json",
+        ]);
+        let output = aws.run(&args)?;
+        let result: DescribeInstances =
+            serde_json::from_str(&output).context("parse describe-instances")?;
+        if let Some(instance) = result
+            .reservations
+            .into_iter()
+            .flat_map(|reservation| reservation.instances)
+            .next()
+        {
+            last_state = instance.state.name;
+            if last_state == target_state {
+                return Ok(());
+            }
+        }
+
+        let now = Instant::now();
+        if now >= deadline {
+            bail!(
+                "timed out waiting for instance {} to reach state '{}' (last seen: '{}')",
+                instance_id,
+                target_state,
+                last_state
+            );
+        }
+        let wait = delay.min(deadline - now);
+        sleep(wait + jitter(wait));
+        delay = (delay * 2).min(Duration::from_secs(20));
+    }
 }
 
 fn wait_for_instance_running(aws: &AwsCli, instance_id: &str) -> Result<()> {
-    let args = aws_args(&[
-        "ec2",
-        "wait",
-        "instance-running",
-        "--instance-ids",
-        instance_id,
-    ]);
-    let _ = aws.run(&args)?;
-    Ok(())
+    wait_for_instance_state(aws, instance_id, "running", INSTANCE_RUNNING_TIMEOUT)
+}
+
+/// Polls `describe-instance-status` (via `describe_ec2_status_checks`) until
+/// both the system and instance status checks pass. A `running` instance
+/// isn't necessarily ready yet: status checks lag behind the state
+/// transition, so callers that need a genuinely healthy instance should wait
+/// here rather than assuming the checks are instantaneous.
+fn wait_for_instance_status_checks_ok(
+    aws: &AwsCli,
+    instance_id: &str,
+    instance_state: &str,
+    timeout: Duration,
+) -> Result<()> {
+    let deadline = Instant::now() + timeout;
+    let mut delay = Duration::from_secs(2);
+    loop {
+        let checks = describe_ec2_status_checks(aws, instance_id, instance_state)?;
+        if checks.checks_pass == Some(true) {
+            return Ok(());
+        }
+        if checks.checks_pass == Some(false) {
+            bail!(
+                "instance {} status checks failed (system={}, instance={})",
+                instance_id,
+                checks.system_status,
+                checks.instance_status
+            );
+        }
+
+        let now = Instant::now();
+        if now >= deadline {
+            bail!(
+                "timed out waiting for instance {} status checks to pass (system={}, instance={})",
+                instance_id,
+                checks.system_status,
+                checks.instance_status
+            );
+        }
+        let wait = delay.min(deadline - now);
+        sleep(wait + jitter(wait));
+        delay = (delay * 2).min(Duration::from_secs(20));
+    }
 }
 
 fn fetch_instance_public_ip(aws: &AwsCli, instance_id: &str) -> Result<Option<String>> {
@@ -4824,16 +10336,48 @@ fn fetch_instance_public_ip(aws: &AwsCli, instance_id: &str) -> Result<Option<St
     Ok(None)
 }
 
-fn terminate_instance(aws: &AwsCli, instance_id: &str) -> Result<()> {
+fn terminate_instance(
+    aws: &AwsCli,
+    config_root: &Path,
+    cluster: &str,
+    name: &str,
+    instance_id: &str,
+) -> Result<()> {
     let args = aws_args(&["ec2", "terminate-instances", "--instance-ids", instance_id]);
-    let _ = aws.run(&args)?;
-    Ok(())
+    let result = aws.run(&args).map(|_| ());
+    record_audit_event(
+        config_root,
+        EC2_PROVIDER,
+        cluster,
+        Some(name),
+        Some(instance_id),
+        "terminate",
+        &args,
+        &result,
+    );
+    result
 }
 
-fn reboot_instance(aws: &AwsCli, instance_id: &str) -> Result<()> {
+fn reboot_instance(
+    aws: &AwsCli,
+    config_root: &Path,
+    cluster: &str,
+    name: &str,
+    instance_id: &str,
+) -> Result<()> {
     let args = aws_args(&["ec2", "reboot-instances", "--instance-ids", instance_id]);
-    let _ = aws.run(&args)?;
-    Ok(())
+    let result = aws.run(&args).map(|_| ());
+    record_audit_event(
+        config_root,
+        EC2_PROVIDER,
+        cluster,
+        Some(name),
+        Some(instance_id),
+        "reboot",
+        &args,
+        &result,
+    );
+    result
 }
 
 fn wait_for_instance_terminated(aws: &AwsCli, instance_id: &str) -> Result<()> {
@@ -4881,6 +10425,187 @@ fn write_ssh_config(
     Ok(())
 }
 
+/// SSH-level reachability probe for `droplet health`/`droplet up --wait-ssh`:
+/// connects to `public_ip:22`, completes an ssh2 handshake and pubkey auth (no
+/// host key verification, matching `ssh_exec_captured`), then runs a trivial
+/// remote command and checks its exit status. Any failure along the way
+/// (connection refused, timeout, auth failure, nonzero exit) is returned as an
+/// `Err` carrying the reason, so callers can treat it as retryable/`degraded`
+/// instead of fatal.
+fn droplet_ssh_probe(public_ip: &str, identity_file: &str) -> Result<()> {
+    let addr = format!("{}:22", public_ip);
+    let tcp = TcpStream::connect(&addr).context("tcp connect failed")?;
+
+    let mut session = Session::new().context("create ssh session")?;
+    session.set_tcp_stream(tcp);
+    session.handshake().context("ssh handshake failed")?;
+    session
+        .userauth_pubkey_file(DEFAULT_INSTANCE_OS_USER, None, Path::new(identity_file), None)
+        .context("ssh authentication failed")?;
+    if !session.authenticated() {
+        bail!("ssh authentication failed");
+    }
+
+    let mut channel = session.channel_session().context("open ssh channel")?;
+    channel.exec("true").context("exec probe command over ssh")?;
+    channel.wait_close().context("close ssh channel")?;
+    let exit_status = channel.exit_status().context("read ssh exit status")?;
+    if exit_status != 0 {
+        bail!("probe command exited with status {}", exit_status);
+    }
+    Ok(())
+}
+
+/// Loops `droplet_ssh_probe` with a fixed 5s sleep (matching `droplet_wait_for_state`'s
+/// 60-attempt shape), returning the last probe error if the deadline is reached.
+fn droplet_wait_for_ssh(public_ip: &str, identity_file: &str) -> Result<()> {
+    let mut last_err = anyhow!("no probe attempts made");
+    for _ in 0..60 {
+        match droplet_ssh_probe(public_ip, identity_file) {
+            Ok(()) => return Ok(()),
+            Err(err) => last_err = err,
+        }
+        sleep(Duration::from_secs(5));
+    }
+    Err(last_err).context("timeout waiting for droplet to accept ssh connections")
+}
+
+fn tcp_connect_with_backoff(addr: &str, timeout: Duration) -> Result<TcpStream> {
+    let deadline = Instant::now() + timeout;
+    let mut delay = Duration::from_secs(1);
+    loop {
+        match TcpStream::connect(addr) {
+            Ok(stream) => return Ok(stream),
+            Err(err) => {
+                let now = Instant::now();
+                if now >= deadline {
+                    return Err(err)
+                        .with_context(|| format!("timed out waiting for {} to accept tcp connections", addr));
+                }
+                sleep(delay.min(deadline - now));
+                delay = (delay * 2).min(Duration::from_secs(30));
+            }
+        }
+    }
+}
+
+fn ssh_exec_command(
+    public_ip: &str,
+    os_user: &str,
+    identity_file: &str,
+    boot_timeout_secs: u64,
+    command: &[String],
+) -> Result<i32> {
+    let (exit_status, stdout, stderr) =
+        ssh_exec_captured(public_ip, os_user, identity_file, boot_timeout_secs, command)?;
+    if !stdout.is_empty() {
+        print!("{}", stdout);
+    }
+    if !stderr.is_empty() {
+        eprint!("{}", stderr);
+    }
+    Ok(exit_status)
+}
+
+/// Like `ssh_exec_command`, but returns the captured stdout/stderr instead of
+/// printing them, so callers fanning out across many hosts can label output
+/// per-host instead of interleaving it.
+fn ssh_exec_captured(
+    public_ip: &str,
+    os_user: &str,
+    identity_file: &str,
+    boot_timeout_secs: u64,
+    command: &[String],
+) -> Result<(i32, String, String)> {
+    let addr = format!("{}:22", public_ip);
+    let tcp = tcp_connect_with_backoff(&addr, Duration::from_secs(boot_timeout_secs))?;
+
+    let mut session = Session::new().context("create ssh session")?;
+    session.set_tcp_stream(tcp);
+    session.handshake().context("ssh handshake failed")?;
+    session
+        .userauth_pubkey_file(os_user, None, Path::new(identity_file), None)
+        .with_context(|| format!("ssh authentication failed for user {}", os_user))?;
+    if !session.authenticated() {
+        bail!("ssh authentication failed for user {}", os_user);
+    }
+
+    let mut channel = session.channel_session().context("open ssh channel")?;
+    let command_line = command.join(" ");
+    channel
+        .exec(&command_line)
+        .with_context(|| format!("exec '{}' over ssh", command_line))?;
+
+    let mut stdout = String::new();
+    channel.read_to_string(&mut stdout).context("read ssh stdout")?;
+    let mut stderr = String::new();
+    channel
+        .stderr()
+        .read_to_string(&mut stderr)
+        .context("read ssh stderr")?;
+    channel.wait_close().context("close ssh channel")?;
+    let exit_status = channel.exit_status().context("read ssh exit status")?;
+
+    Ok((exit_status, stdout, stderr))
+}
+
+/// Runs `command` over SSH on every `(name, public_ip)` target concurrently,
+/// printing each host's output labeled with `name=`/`public-ip=`, and bails with
+/// the aggregated list of failed hosts if any connection or exit code failed.
+fn fan_out_ssh_exec(
+    targets: Vec<(String, Option<String>)>,
+    os_user: &str,
+    identity_file: &str,
+    boot_timeout_secs: u64,
+    command: &[String],
+    concurrency: usize,
+) -> Result<()> {
+    let total = targets.len();
+    let results = run_with_concurrency(targets, concurrency, |(name, public_ip)| {
+        let outcome = match public_ip.as_deref() {
+            Some(ip) => ssh_exec_captured(ip, os_user, identity_file, boot_timeout_secs, command),
+            None => Err(anyhow!("instance '{}' has no public ip", name)),
+        };
+        (name, public_ip, outcome)
+    });
+
+    let mut failed = Vec::new();
+    for (name, public_ip, outcome) in results {
+        let public_ip = public_ip.as_deref().unwrap_or("N/A").to_string();
+        match outcome {
+            Ok((exit_code, stdout, stderr)) => {
+                println!(
+                    "name={} public-ip={} exit-code={}",
+                    name, public_ip, exit_code
+                );
+                if !stdout.is_empty() {
+                    print!("{}", stdout);
+                }
+                if !stderr.is_empty() {
+                    eprint!("{}", stderr);
+                }
+                if exit_code != 0 {
+                    failed.push(format!("{} (exit {})", name, exit_code));
+                }
+            }
+            Err(err) => {
+                println!("name={} public-ip={} error={}", name, public_ip, err);
+                failed.push(format!("{} ({})", name, err));
+            }
+        }
+    }
+
+    if !failed.is_empty() {
+        bail!(
+            "exec failed on {} of {} host(s): {}",
+            failed.len(),
+            total,
+            failed.join(", ")
+        );
+    }
+    Ok(())
+}
+
 fn confirm(prompt: &str) -> Result<bool> {
     print!("{}", prompt);
     io::stdout().flush().context("flush stdout")?;
@@ -4892,6 +10617,180 @@ fn confirm(prompt: &str) -> Result<bool> {
     Ok(response == "y" || response == "yes")
 }
 
+/// Prompts for a config field during `init --wizard`, offering `default` if the
+/// user presses enter without typing anything. `choices`, when non-empty, is
+/// printed as a hint (e.g. the provider's available regions/zones) but any
+/// value is still accepted.
+fn prompt_wizard_field(label: &str, default: &str, choices: &[String]) -> Result<String> {
+    if !choices.is_empty() {
+        println!("  {} choices: {}", label, choices.join(", "));
+    }
+    if default.is_empty() {
+        print!("  {}: ", label);
+    } else {
+        print!("  {} [{}]: ", label, default);
+    }
+    io::stdout().flush().context("flush stdout")?;
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .context("read wizard input")?;
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        Ok(default.to_string())
+    } else {
+        Ok(trimmed.to_string())
+    }
+}
+
+/// Like `prompt_wizard_field`, but specifically for `ssh_public_key_path`:
+/// re-prompts until the answer expands (via `expand_home_path`) to a file
+/// that actually exists, so the wizard can't hand back a config that fails
+/// the very first `up` with a missing-key error. Reads stdin directly
+/// (rather than going through `prompt_wizard_field`) so it can detect EOF
+/// and bail instead of re-prompting forever against a non-interactive
+/// stdin (piped input, CI, `/dev/null`) that never satisfies the check.
+fn prompt_wizard_public_key_path(default: &str) -> Result<String> {
+    loop {
+        if default.is_empty() {
+            print!("  ssh_public_key_path: ");
+        } else {
+            print!("  ssh_public_key_path [{}]: ", default);
+        }
+        io::stdout().flush().context("flush stdout")?;
+        let mut input = String::new();
+        let bytes_read = io::stdin()
+            .read_line(&mut input)
+            .context("read wizard input")?;
+        if bytes_read == 0 {
+            bail!("unexpected end of input while prompting for ssh_public_key_path");
+        }
+        let trimmed = input.trim();
+        let candidate = if trimmed.is_empty() {
+            default.to_string()
+        } else {
+            trimmed.to_string()
+        };
+        match expand_home_path(&candidate) {
+            Ok(expanded) if expanded.exists() => return Ok(candidate),
+            Ok(expanded) => println!("  {} does not exist; try again", expanded.display()),
+            Err(err) => println!("  invalid path: {:#}", err),
+        }
+    }
+}
+
+/// Prompts for a config field during `init --interactive`, presenting
+/// `choices` as a numbered menu and validating the final answer against it.
+/// Falls back to `prompt_wizard_field`'s free-text behavior when `choices` is
+/// empty (e.g. the live catalog fetch failed or returned nothing).
+fn prompt_catalog_choice(label: &str, default: &str, choices: &[String]) -> Result<String> {
+    if choices.is_empty() {
+        return prompt_wizard_field(label, default, choices);
+    }
+
+    println!("  {}:", label);
+    for (index, choice) in choices.iter().enumerate() {
+        println!("    {}) {}", index + 1, choice);
+    }
+    let default_prompt = if choices.iter().any(|choice| choice == default) {
+        default.to_string()
+    } else {
+        choices[0].clone()
+    };
+    loop {
+        print!("  {} [{}]: ", label, default_prompt);
+        io::stdout().flush().context("flush stdout")?;
+        let mut input = String::new();
+        io::stdin()
+            .read_line(&mut input)
+            .context("read wizard input")?;
+        let trimmed = input.trim();
+        if trimmed.is_empty() {
+            return Ok(default_prompt);
+        }
+        if let Ok(index) = trimmed.parse::<usize>() {
+            if index >= 1 && index <= choices.len() {
+                return Ok(choices[index - 1].clone());
+            }
+        }
+        if choices.iter().any(|choice| choice == trimmed) {
+            return Ok(trimmed.to_string());
+        }
+        println!("  '{}' is not one of the listed {} values; pick a number or an exact slug", trimmed, label);
+    }
+}
+
+/// Writes `contents` to `path` and restricts it to owner read/write (0600),
+/// since cluster config.toml files can contain key fingerprints and project
+/// identifiers.
+fn write_config_secured(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).with_context(|| format!("write {}", path.display()))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut permissions = fs::metadata(path)
+            .with_context(|| format!("stat {}", path.display()))?
+            .permissions();
+        permissions.set_mode(0o600);
+        fs::set_permissions(path, permissions)
+            .with_context(|| format!("chmod 0600 {}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// Resolves the `--user-data`/`--user-data-inline` pair shared by every
+/// provider's `up` command into the literal payload to send at boot. Exactly
+/// one of the two may be given.
+fn resolve_user_data(
+    user_data_path: Option<&str>,
+    user_data_inline: Option<&str>,
+) -> Result<Option<String>> {
+    match (user_data_path, user_data_inline) {
+        (Some(_), Some(_)) => bail!("pass only one of --user-data or --user-data-inline"),
+        (Some(path), None) => {
+            let contents =
+                fs::read_to_string(path).with_context(|| format!("read user-data {}", path))?;
+            Ok(Some(contents))
+        }
+        (None, Some(inline)) => Ok(Some(inline.to_string())),
+        (None, None) => Ok(None),
+    }
+}
+
+/// Merges extra `--ssh-key` public key files into `user_data` as an additional
+/// `ssh_authorized_keys` block, so multiple operators can be granted access at
+/// creation time alongside the provider's own key-injection mechanism. This is
+/// a textual append, not a YAML merge, so a hand-written non-cloud-config
+/// `user_data` combined with `--ssh-key` will not parse as valid cloud-config.
+fn merge_ssh_authorized_keys(user_data: Option<String>, ssh_key_paths: &[String]) -> Result<Option<String>> {
+    if ssh_key_paths.is_empty() {
+        return Ok(user_data);
+    }
+
+    let mut keys = Vec::new();
+    for path in ssh_key_paths {
+        let contents =
+            fs::read_to_string(path).with_context(|| format!("read ssh key {}", path))?;
+        let trimmed = contents.trim();
+        if !trimmed.is_empty() {
+            keys.push(trimmed.to_string());
+        }
+    }
+    if keys.is_empty() {
+        return Ok(user_data);
+    }
+
+    let mut combined = user_data.unwrap_or_else(|| "#cloud-config\n".to_string());
+    if !combined.ends_with('\n') {
+        combined.push('\n');
+    }
+    combined.push_str("ssh_authorized_keys:\n");
+    for key in &keys {
+        combined.push_str(&format!("  - {}\n", key));
+    }
+    Ok(Some(combined))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4929,6 +10828,15 @@ mod tests {
         }
     }
 
+    fn tcp_probe_stub(banner: ProbeOutcome, banner_reason: Option<&str>) -> TcpSshProbeResult {
+        TcpSshProbeResult {
+            tcp_ssh_banner: banner,
+            tcp_ssh_banner_reason: banner_reason.map(|reason| reason.to_string()),
+            ssh_handshake: banner,
+            ssh_handshake_reason: None,
+        }
+    }
+
     #[test]
     fn cli_parses_health_command_defaults() {
         let cli = Cli::try_parse_from(["vmcli", "ec2", "health", "dev-cluster", "web-1"])
@@ -4939,7 +10847,7 @@ mod tests {
             TopCommand::Ec2(ec2) => match ec2.command {
                 Ec2Command::Health(args) => {
                     assert_eq!(args.cluster, "dev-cluster");
-                    assert_eq!(args.name, "web-1");
+                    assert_eq!(args.name.as_deref(), Some("web-1"));
                     assert_eq!(args.os_user, DEFAULT_INSTANCE_OS_USER);
                     assert!(args.config.is_none());
                 }
@@ -5170,7 +11078,8 @@ mod tests {
     #[test]
     fn summarize_health_ok_when_control_plane_probe_succeeds() {
         let eic = eic_probe_stub(SgPort22Status::OpenWorld, ProbeOutcome::Success);
-        let summary = summarize_health("running", Some(true), &eic);
+        let tcp = tcp_probe_stub(ProbeOutcome::Success, None);
+        let summary = summarize_health("running", Some(true), &eic, &tcp);
         assert_eq!(summary.level, HealthLevel::Ok);
         assert_eq!(summary.ssh_local_problem_likely, Some(true));
     }
@@ -5178,7 +11087,8 @@ mod tests {
     #[test]
     fn summarize_health_degraded_when_ssh_port_closed() {
         let eic = eic_probe_stub(SgPort22Status::Closed, ProbeOutcome::Failed);
-        let summary = summarize_health("running", Some(true), &eic);
+        let tcp = tcp_probe_stub(ProbeOutcome::Failed, Some("connection-refused"));
+        let summary = summarize_health("running", Some(true), &eic, &tcp);
         assert_eq!(summary.level, HealthLevel::Degraded);
         assert_eq!(summary.notes, "security-group-port-22-closed");
         assert_eq!(summary.ssh_local_problem_likely, Some(false));
@@ -5187,7 +11097,8 @@ mod tests {
     #[test]
     fn summarize_health_unreachable_when_instance_not_running() {
         let eic = eic_probe_stub(SgPort22Status::Restricted, ProbeOutcome::Skipped);
-        let summary = summarize_health("stopped", None, &eic);
+        let tcp = tcp_probe_stub(ProbeOutcome::Skipped, Some("no-public-ip"));
+        let summary = summarize_health("stopped", None, &eic, &tcp);
         assert_eq!(summary.level, HealthLevel::Unreachable);
         assert_eq!(summary.notes, "instance-not-running");
     }
@@ -5195,7 +11106,8 @@ mod tests {
     #[test]
     fn summarize_health_unknown_when_checks_unknown_and_no_probe_success() {
         let eic = eic_probe_stub(SgPort22Status::Restricted, ProbeOutcome::Skipped);
-        let summary = summarize_health("running", None, &eic);
+        let tcp = tcp_probe_stub(ProbeOutcome::Skipped, Some("no-public-ip"));
+        let summary = summarize_health("running", None, &eic, &tcp);
         assert_eq!(summary.level, HealthLevel::Unknown);
         assert_eq!(summary.ssh_local_problem_likely, None);
     }
@@ -5230,4 +11142,148 @@ mod tests {
         let path = default_ssh_public_key_path(Path::new("/tmp/vmcli-alt-config"));
         assert_eq!(path, "/tmp/vmcli-alt-config/vmcli.pub");
     }
+
+    #[test]
+    fn levenshtein_distance_counts_edits() {
+        assert_eq!(levenshtein_distance("prod", "prod"), 0);
+        assert_eq!(levenshtein_distance("prd", "prod"), 1);
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn closest_match_suggests_nearby_candidate() {
+        let candidates = vec!["prod".to_string(), "staging".to_string(), "dev".to_string()];
+        assert_eq!(closest_match("prd", &candidates), Some("prod"));
+    }
+
+    #[test]
+    fn closest_match_rejects_unrelated_input() {
+        let candidates = vec!["prod".to_string(), "staging".to_string()];
+        assert_eq!(closest_match("xyzzy", &candidates), None);
+    }
+
+    #[test]
+    fn redact_audit_args_strips_user_data_payload() {
+        let args = vec![
+            "ec2".to_string(),
+            "run-instances".to_string(),
+            "--user-data".to_string(),
+            "#cloud-config\nbootstrap_token: s3cr3t".to_string(),
+            "--instance-type".to_string(),
+            "t3.micro".to_string(),
+        ];
+        let redacted = redact_audit_args(&args);
+        assert_eq!(redacted[2], "--user-data");
+        assert_eq!(redacted[3], "<redacted>");
+        assert_eq!(redacted[5], "t3.micro");
+    }
+
+    #[test]
+    fn redact_audit_args_passes_through_when_no_secret_flags() {
+        let args = vec!["ec2".to_string(), "terminate-instances".to_string()];
+        assert_eq!(redact_audit_args(&args), args);
+    }
+
+    #[test]
+    fn is_retryable_aws_error_matches_known_throttling_codes() {
+        assert!(is_retryable_aws_error("An error occurred (RequestLimitExceeded)"));
+        assert!(is_retryable_aws_error("Throttling: rate exceeded"));
+        assert!(is_retryable_aws_error("ServiceUnavailable"));
+    }
+
+    #[test]
+    fn is_retryable_aws_error_rejects_other_errors() {
+        assert!(!is_retryable_aws_error("InvalidParameterValue: bad AMI id"));
+        assert!(!is_retryable_aws_error("UnauthorizedOperation"));
+    }
+
+    #[test]
+    fn jitter_stays_within_max() {
+        for _ in 0..20 {
+            let max = Duration::from_secs(10);
+            let j = jitter(max);
+            assert!(j <= max, "jitter {:?} exceeded max {:?}", j, max);
+        }
+    }
+
+    #[test]
+    fn jitter_of_zero_is_zero() {
+        assert_eq!(jitter(Duration::ZERO), Duration::ZERO);
+    }
+
+    #[test]
+    fn journal_input_hash_is_stable_for_same_input() {
+        assert_eq!(journal_input_hash("cluster=prod"), journal_input_hash("cluster=prod"));
+    }
+
+    #[test]
+    fn journal_input_hash_differs_for_different_input() {
+        assert_ne!(journal_input_hash("cluster=prod"), journal_input_hash("cluster=staging"));
+    }
+
+    #[test]
+    fn aws_config_file_region_reads_profile_section() {
+        let path = std::env::temp_dir().join(format!("vmcli-test-aws-config-{}", std::process::id()));
+        fs::write(
+            &path,
+            "[default]\nregion = us-east-1\n\n[profile staging]\nregion = eu-west-1\n",
+        )
+        .expect("write test aws config");
+        env::set_var("AWS_CONFIG_FILE", &path);
+        let default_region = aws_config_file_region(None);
+        let staging_region = aws_config_file_region(Some("staging"));
+        let missing_region = aws_config_file_region(Some("nonexistent"));
+        env::remove_var("AWS_CONFIG_FILE");
+        fs::remove_file(&path).ok();
+        assert_eq!(default_region, Some("us-east-1".to_string()));
+        assert_eq!(staging_region, Some("eu-west-1".to_string()));
+        assert_eq!(missing_region, None);
+    }
+
+    #[test]
+    fn firewall_rule_key_distinguishes_source_kinds() {
+        let mut cidr_rule = firewall_rule(22);
+        cidr_rule.cidr = "10.0.0.0/8".to_string();
+        assert_eq!(
+            firewall_rule_key(&cidr_rule),
+            ("tcp".to_string(), 22, 22, "cidr:10.0.0.0/8".to_string())
+        );
+
+        let mut sg_rule = firewall_rule(22);
+        sg_rule.source_security_group = Some("sg-123".to_string());
+        assert_eq!(
+            firewall_rule_key(&sg_rule),
+            ("tcp".to_string(), 22, 22, "sg:sg-123".to_string())
+        );
+
+        let mut prefix_list_rule = firewall_rule(22);
+        prefix_list_rule.prefix_list = Some("pl-456".to_string());
+        assert_eq!(
+            firewall_rule_key(&prefix_list_rule),
+            ("tcp".to_string(), 22, 22, "pl:pl-456".to_string())
+        );
+    }
+
+    #[test]
+    fn firewall_rule_key_uses_to_port_when_present() {
+        let mut rule = firewall_rule(8000);
+        rule.to_port = Some(8100);
+        assert_eq!(
+            firewall_rule_key(&rule),
+            ("tcp".to_string(), 8000, 8100, "cidr:0.0.0.0/0".to_string())
+        );
+    }
+
+    #[test]
+    fn is_spot_capacity_error_matches_known_rejection_codes() {
+        assert!(is_spot_capacity_error("InsufficientInstanceCapacity"));
+        assert!(is_spot_capacity_error("SpotMaxPriceTooLow: price too low"));
+        assert!(is_spot_capacity_error("Spot request is unfulfillable"));
+    }
+
+    #[test]
+    fn is_spot_capacity_error_rejects_unrelated_errors() {
+        assert!(!is_spot_capacity_error("InvalidAMIID.NotFound"));
+        assert!(!is_spot_capacity_error("InvalidParameterValue: duplicate name"));
+    }
 }